@@ -0,0 +1,23 @@
+use bevy_resolution::prelude::*;
+
+fn main() {
+    let base = r360p(AspectRatio::SIXTEEN_NINE);
+    let target = r1080p(AspectRatio::SIXTEEN_NINE);
+
+    println!("Base resolution: {}", base);
+    println!(
+        "Does {} integer scale to {}? {}",
+        base,
+        target,
+        base.has_integer_scale(&target)
+    );
+    println!("Scale factor: {}", base.scale_factor(&target));
+
+    for common in CommonResolutions::iter() {
+        println!("16:9 {}", Resolution::from_common(common));
+    }
+
+    for common in common4x3::CommonResolutions::iter() {
+        println!("4:3 {}", Resolution::from_common_4x3(common));
+    }
+}
@@ -9,7 +9,7 @@ fn main() {
         r360p(AspectRatio::FOUR_THREE),
         r720p(AspectRatio::SIXTEEN_NINE),
     ]
-    .map(|r| WindowResolution::from(r));
+    .map(WindowResolution::from);
 
     for resolutions in supported_resolutions {
         println!("{:?}", resolutions);
@@ -24,6 +24,27 @@ impl CommonResolutions {
         ]
         .into_iter()
     }
+
+    /// Returns the [`CommonResolutions`] variant with the smallest area
+    pub const fn smallest() -> CommonResolutions {
+        CommonResolutions::R360p
+    }
+
+    /// Returns the [`CommonResolutions`] variant with the largest area
+    pub const fn largest() -> CommonResolutions {
+        CommonResolutions::R1440p
+    }
+
+    /// Returns the [`CommonResolutions`] variant with the given height, if there is one
+    pub const fn from_height(height: u32) -> Option<CommonResolutions> {
+        match height {
+            360 => Some(CommonResolutions::R360p),
+            720 => Some(CommonResolutions::R720p),
+            1080 => Some(CommonResolutions::R1080p),
+            1440 => Some(CommonResolutions::R1440p),
+            _ => None,
+        }
+    }
 }
 
 impl From<CommonResolutions> for Resolution {
@@ -105,6 +126,16 @@ pub mod common4x3 {
             ]
             .into_iter()
         }
+
+        /// Returns the [`CommonResolutions`] variant with the smallest area
+        pub const fn smallest() -> CommonResolutions {
+            CommonResolutions::R360p
+        }
+
+        /// Returns the [`CommonResolutions`] variant with the largest area
+        pub const fn largest() -> CommonResolutions {
+            CommonResolutions::R1440p
+        }
     }
 
     impl Display for CommonResolutions {
@@ -167,10 +198,16 @@ pub mod common4x3 {
         fn iter() {
             let iter = CommonResolutions::iter().collect::<Vec<CommonResolutions>>();
 
-            assert_eq!(iter[0], CommonResolutions::R360p.into());
-            assert_eq!(iter[1], CommonResolutions::R480p.into());
-            assert_eq!(iter[2], CommonResolutions::R720p.into());
-            assert_eq!(iter[3], CommonResolutions::R1440p.into());
+            assert_eq!(iter[0], CommonResolutions::R360p);
+            assert_eq!(iter[1], CommonResolutions::R480p);
+            assert_eq!(iter[2], CommonResolutions::R720p);
+            assert_eq!(iter[3], CommonResolutions::R1440p);
+        }
+
+        #[test]
+        fn smallest_largest() {
+            assert_eq!(CommonResolutions::smallest(), CommonResolutions::R360p);
+            assert_eq!(CommonResolutions::largest(), CommonResolutions::R1440p);
         }
 
         #[cfg(feature = "bevy_window")]
@@ -220,10 +257,26 @@ mod tests {
     fn iter() {
         let iter = CommonResolutions::iter().collect::<Vec<CommonResolutions>>();
 
-        assert_eq!(iter[0], CommonResolutions::R360p.into());
-        assert_eq!(iter[1], CommonResolutions::R720p.into());
-        assert_eq!(iter[2], CommonResolutions::R1080p.into());
-        assert_eq!(iter[3], CommonResolutions::R1440p.into());
+        assert_eq!(iter[0], CommonResolutions::R360p);
+        assert_eq!(iter[1], CommonResolutions::R720p);
+        assert_eq!(iter[2], CommonResolutions::R1080p);
+        assert_eq!(iter[3], CommonResolutions::R1440p);
+    }
+
+    #[test]
+    fn smallest_largest() {
+        assert_eq!(CommonResolutions::smallest(), CommonResolutions::R360p);
+        assert_eq!(CommonResolutions::largest(), CommonResolutions::R1440p);
+    }
+
+    #[test]
+    fn from_height() {
+        for common in CommonResolutions::iter() {
+            let height = UVec2::from(common).y;
+
+            assert_eq!(CommonResolutions::from_height(height), Some(common));
+        }
+        assert_eq!(CommonResolutions::from_height(480), None);
     }
 
     #[cfg(feature = "bevy_window")]
@@ -0,0 +1,78 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Errors that can occur when creating or modifying a [`Resolution`](crate::resolutions::Resolution)
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum ResolutionError {
+    /// An aspect ratio's width or height is zero, negative, infinite, or NaN
+    InvalidAspectRatio { width: f32, height: f32 },
+    /// An operation would have changed a resolution's set aspect ratio from `expected` to `got`
+    AspectRatioBroken { expected: f32, got: f32 },
+    /// Bytes passed to [`Resolution::from_bytes`](crate::resolutions::Resolution::from_bytes) are
+    /// truncated or have an unknown aspect ratio tag
+    InvalidEncoding,
+}
+
+impl Display for ResolutionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolutionError::InvalidAspectRatio { width, height } => {
+                write!(f, "invalid aspect ratio: {}:{}", width, height)
+            }
+            ResolutionError::AspectRatioBroken { expected, got } => {
+                write!(f, "aspect ratio would change from {} to {}", expected, got)
+            }
+            ResolutionError::InvalidEncoding => {
+                write!(f, "invalid resolution encoding")
+            }
+        }
+    }
+}
+
+impl Error for ResolutionError {}
+
+/// Errors that can occur when parsing a [`Resolution`](crate::resolutions::Resolution) from a string
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ResolutionParseError {
+    /// The input is not of the form `WIDTHxHEIGHT`
+    InvalidFormat,
+    /// A width or height is not a positive, finite number
+    InvalidDimension(String),
+    /// The aspect ratio is not of the form `W:H` or a positive, finite ratio
+    InvalidAspectRatio(String),
+    /// The entry at `index` of a comma-separated list failed to parse
+    InvalidListEntry {
+        index: usize,
+        error: Box<ResolutionParseError>,
+    },
+}
+
+impl Display for ResolutionParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ResolutionParseError::InvalidFormat => {
+                write!(f, "invalid resolution: expected WIDTHxHEIGHT")
+            }
+            ResolutionParseError::InvalidDimension(dimension) => {
+                write!(f, "invalid resolution dimension: {}", dimension)
+            }
+            ResolutionParseError::InvalidAspectRatio(ratio) => {
+                write!(f, "invalid aspect ratio: {}", ratio)
+            }
+            ResolutionParseError::InvalidListEntry { index, error } => {
+                write!(f, "invalid resolution at index {}: {}", index, error)
+            }
+        }
+    }
+}
+
+impl Error for ResolutionParseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ResolutionParseError::InvalidListEntry { error, .. } => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
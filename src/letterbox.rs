@@ -0,0 +1,79 @@
+//! Automatic letterboxing and pillarboxing of cameras to a design resolution.
+//!
+//! Cameras with a [`LetterboxCamera`] have their viewport constrained to the largest centered
+//! region of the primary window that matches the design resolution's aspect ratio. The area outside
+//! the viewport is left to the clear color, producing the bars.
+use crate::resolutions::Resolution;
+use bevy_camera::{Camera, Viewport};
+use bevy_ecs::prelude::{Component, Query, With};
+use bevy_window::{PrimaryWindow, Window};
+
+/// Constrains a camera's viewport to the aspect ratio of `design` within the primary window.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct LetterboxCamera {
+    pub design: Resolution,
+}
+
+/// Updates the viewport of every [`LetterboxCamera`] to fit the primary window.
+pub fn letterbox_cameras(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    mut cameras: Query<(&LetterboxCamera, &mut Camera)>,
+) {
+    let Ok(window) = windows.single() else {
+        return;
+    };
+    let window_resolution = Resolution::new(
+        window.physical_width() as f32,
+        window.physical_height() as f32,
+    );
+
+    for (letterbox, mut camera) in &mut cameras {
+        let (position, size) = letterbox.design.viewport_rect(&window_resolution);
+
+        camera.viewport = Some(Viewport {
+            physical_position: position.as_uvec2(),
+            physical_size: size.as_uvec2(),
+            ..camera.viewport.clone().unwrap_or_default()
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_ecs::world::World;
+    use bevy_math::{AspectRatio, UVec2};
+    use bevy_window::WindowResolution;
+
+    #[test]
+    fn letterbox_viewport() {
+        let mut world = World::new();
+        world.spawn((
+            Window {
+                resolution: WindowResolution::new(800, 600),
+                ..Default::default()
+            },
+            PrimaryWindow,
+        ));
+        let camera = world
+            .spawn((
+                Camera::default(),
+                LetterboxCamera {
+                    design: Resolution::from_height(1080., AspectRatio::SIXTEEN_NINE),
+                },
+            ))
+            .id();
+
+        world.run_system_once(letterbox_cameras).unwrap();
+
+        let viewport = world
+            .get::<Camera>(camera)
+            .unwrap()
+            .viewport
+            .clone()
+            .unwrap();
+        assert_eq!(viewport.physical_position, UVec2::new(0, 75));
+        assert_eq!(viewport.physical_size, UVec2::new(800, 450));
+    }
+}
@@ -12,9 +12,30 @@
 //!
 //! ## Feature Flags
 //! This crate exposes the following feature flags:
-//! - `bevy_window`: Enables support for usage with `bevy_window`, this is required for usage with `bevy_window::WindowResolution`
+//! - `bevy_window`: Enables support for usage with `bevy_window`, this is required for usage with `bevy_window::WindowResolution`, and for using `Resolution` as a component
+//! - `bevy_camera`: Enables support for usage with `bevy_camera`, this is required for automatic letterboxing of cameras
+//! - `bevy_reflect`: Enables support for usage with `bevy_reflect`, this is required for using `Resolution` in reflection and scenes
+//! - `bevy_render`: Enables support for usage with `bevy_render`, this is required for converting resolutions into texture extents
+//! - `tween`: Enables `bevy_window` and support for usage with `bevy_time`, this is required for animating window resizes
 //!
 //! The `bevy_window` feature is enabled by default.
 //!
 pub mod common;
+pub mod error;
+#[cfg(feature = "bevy_camera")]
+pub mod letterbox;
 pub mod resolutions;
+#[cfg(feature = "tween")]
+pub mod tween;
+#[cfg(feature = "bevy_window")]
+pub mod window;
+
+/// Re-exports the most commonly used types and functions, `use bevy_resolution::prelude::*;`
+pub mod prelude {
+    pub use crate::common::{common4x3, CommonResolutions};
+    pub use crate::resolutions::{
+        fits_aspect_ratio, has_integer_scale, r1080p, r1440p, r360p, r480p, r720p,
+        resolution_fits_aspect_ratio, Resolution,
+    };
+    pub use bevy_math::AspectRatio;
+}
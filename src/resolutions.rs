@@ -1,8 +1,22 @@
+use crate::common::{common4x3, CommonResolutions};
+use crate::error::{ResolutionError, ResolutionParseError};
 use bevy_math::{AspectRatio, UVec2, Vec2};
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
 use std::fmt::{Display, Formatter};
+use std::ops::{Div, DivAssign};
+use std::str::FromStr;
+
+const EPSILON: f32 = 1e-4;
 
 /// Represents a specific resolution
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "bevy_window", derive(bevy_ecs::component::Component))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
 pub struct Resolution {
     width: f32,
     height: f32,
@@ -10,6 +24,11 @@ pub struct Resolution {
 }
 
 #[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, PartialEq, Clone)
+)]
 enum AspectRatioMode {
     Dynamic,
     Set(AspectRatio),
@@ -21,6 +40,128 @@ impl AspectRatioMode {
     }
 }
 
+/// A commonly named aspect ratio, such as 16:9.
+struct NamedRatio {
+    name: &'static str,
+    width: u32,
+    height: u32,
+    ratio: AspectRatio,
+}
+
+const fn named_ratio(name: &'static str, width: u32, height: u32) -> NamedRatio {
+    match AspectRatio::try_from_pixels(width, height) {
+        Ok(ratio) => NamedRatio {
+            name,
+            width,
+            height,
+            ratio,
+        },
+        Err(_) => panic!("named aspect ratios must be valid"),
+    }
+}
+
+/// Commonly named aspect ratios, used for labeling and snapping resolutions.
+const NAMED_RATIOS: [NamedRatio; 6] = [
+    named_ratio("1:1", 1, 1),
+    named_ratio("4:3", 4, 3),
+    named_ratio("16:10", 16, 10),
+    named_ratio("16:9", 16, 9),
+    named_ratio("21:9", 21, 9),
+    named_ratio("32:9", 32, 9),
+];
+
+/// Aspect ratios with their own [`Resolution::to_bytes`] tag, their index plus one.
+///
+/// Kept separate from [`NAMED_RATIOS`] because the tags are part of the encoding, entries may only
+/// ever be appended.
+const ENCODED_RATIOS: [AspectRatio; 6] = [
+    named_ratio("1:1", 1, 1).ratio,
+    named_ratio("4:3", 4, 3).ratio,
+    named_ratio("16:10", 16, 10).ratio,
+    named_ratio("16:9", 16, 9).ratio,
+    named_ratio("21:9", 21, 9).ratio,
+    named_ratio("32:9", 32, 9).ratio,
+];
+
+/// The [`Resolution::to_bytes`] tag for a dynamic aspect ratio.
+const DYNAMIC_TAG: u8 = 0;
+/// The [`Resolution::to_bytes`] tag for a set aspect ratio taken from the encoded dimensions.
+const DIMENSIONS_TAG: u8 = u8::MAX;
+
+fn approx_eq(a: f32, b: f32) -> bool {
+    (a - b).abs() <= EPSILON
+}
+
+/// Finds the `width:height` pair that exactly reproduces `ratio`, if any.
+///
+/// Commonly named ratios keep their conventional form (16:10 rather than 8:5), otherwise the
+/// smallest such pair is used.
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+fn simplify_ratio(ratio: f32) -> Option<(u32, u32)> {
+    const MAX_DENOMINATOR: u64 = 10_000;
+
+    if let Some(named) = NAMED_RATIOS
+        .iter()
+        .find(|named| named.ratio.ratio() == ratio)
+    {
+        return Some((named.width, named.height));
+    }
+
+    let (mut h0, mut h1) = (0u64, 1u64);
+    let (mut k0, mut k1) = (1u64, 0u64);
+    let mut x = ratio as f64;
+
+    for _ in 0..32 {
+        let a = x.floor();
+        let (h2, k2) = (a as u64 * h1 + h0, a as u64 * k1 + k0);
+
+        if k2 > MAX_DENOMINATOR || h2 > u32::MAX as u64 {
+            break;
+        }
+        if h2 as f32 / k2 as f32 == ratio {
+            return Some((h2 as u32, k2 as u32));
+        }
+
+        (h0, h1, k0, k1) = (h1, h2, k1, k2);
+        let fract = x - a;
+        if fract <= f64::EPSILON {
+            break;
+        }
+        x = 1. / fract;
+    }
+
+    None
+}
+
+fn parse_dimension(s: &str) -> Result<f32, ResolutionParseError> {
+    match s.trim().parse::<f32>() {
+        Ok(value) if value.is_finite() && value > 0. => Ok(value),
+        _ => Err(ResolutionParseError::InvalidDimension(s.to_string())),
+    }
+}
+
+fn parse_aspect_ratio(s: &str) -> Result<AspectRatio, ResolutionParseError> {
+    let invalid = || ResolutionParseError::InvalidAspectRatio(s.to_string());
+    let (width, height) = match s.split_once(':') {
+        Some((width, height)) => (width.trim().parse::<f32>(), height.trim().parse::<f32>()),
+        None => (s.trim().parse::<f32>(), Ok(1.)),
+    };
+
+    match (width, height) {
+        (Ok(width), Ok(height)) if width > 0. && height > 0. => {
+            AspectRatio::try_new(width, height).map_err(|_| invalid())
+        }
+        _ => Err(invalid()),
+    }
+}
+
 impl Resolution {
     pub fn new(width: f32, height: f32) -> Self {
         Resolution {
@@ -46,10 +187,310 @@ impl Resolution {
         }
     }
 
+    /// Creates a resolution with `aspect_ratio` whose area is `budget_pixels`, such as the number of
+    /// pixels the GPU can shade per frame.
+    pub fn from_pixel_budget(budget_pixels: u64, aspect_ratio: AspectRatio) -> Self {
+        Resolution::from_height(
+            (budget_pixels as f32 / aspect_ratio.ratio()).sqrt(),
+            aspect_ratio,
+        )
+    }
+
+    /// Creates a resolution at `height` with the commonly named aspect ratio called `ratio_name`,
+    /// one of `1:1`, `4:3`, `16:10`, `16:9`, `21:9`, or `32:9`.
+    ///
+    /// Fails with [`ResolutionParseError::InvalidAspectRatio`] if `ratio_name` isn't one of them.
+    pub fn from_height_named(height: f32, ratio_name: &str) -> Result<Self, ResolutionParseError> {
+        NAMED_RATIOS
+            .iter()
+            .find(|named| named.name == ratio_name.trim())
+            .map(|named| Resolution::from_height(height, named.ratio))
+            .ok_or_else(|| ResolutionParseError::InvalidAspectRatio(ratio_name.to_string()))
+    }
+
+    /// Creates one resolution at `height` for each of `ratios`, such as 1280x720, 960x720, and
+    /// 1152x720 for 16:9, 4:3, and 16:10 at a height of 720.
+    pub fn at_height_for_ratios(height: f32, ratios: &[AspectRatio]) -> Vec<Self> {
+        ratios
+            .iter()
+            .map(|ratio| Resolution::from_height(height, *ratio))
+            .collect()
+    }
+
+    /// Creates a resolution close to `width` pixels wide whose width and height are both whole
+    /// numbers that exactly match `aspect_ratio`.
+    ///
+    /// The width is snapped to the nearest multiple of the fully reduced form of the ratio's
+    /// [`Resolution::simplified_ratio`], such as a multiple of 16 for 16:9 or 8 for 16:10. Ratios
+    /// that can't be simplified have both dimensions rounded instead, which only matches the ratio
+    /// approximately.
+    pub fn from_width_exact(width: f32, aspect_ratio: AspectRatio) -> Self {
+        match simplify_ratio(aspect_ratio.ratio()) {
+            Some((ratio_w, ratio_h)) => {
+                let divisor = gcd(ratio_w as u64, ratio_h as u64) as u32;
+                let (ratio_w, ratio_h) = (ratio_w / divisor, ratio_h / divisor);
+                let multiple = (width / ratio_w as f32).round().max(1.);
+                Resolution {
+                    width: multiple * ratio_w as f32,
+                    height: multiple * ratio_h as f32,
+                    aspect_ratio: AspectRatioMode::Set(aspect_ratio),
+                }
+            }
+            None => Resolution::from_width(width, aspect_ratio).round(),
+        }
+    }
+
+    /// Creates a resolution from raw dimensions, setting its aspect ratio if the dimensions match
+    /// a commonly named ratio (1:1, 4:3, 16:10, 16:9, 21:9, or 32:9), and leaving it dynamic
+    /// otherwise.
+    pub fn from_dimensions_detect(width: f32, height: f32) -> Self {
+        let aspect_ratio = NAMED_RATIOS
+            .iter()
+            .find(|named| approx_eq(width / height, named.ratio.ratio()))
+            .map_or(AspectRatioMode::Dynamic, |named| {
+                AspectRatioMode::Set(named.ratio)
+            });
+
+        Resolution {
+            width,
+            height,
+            aspect_ratio,
+        }
+    }
+
+    /// Creates a resolution from one of the 16:9 [`CommonResolutions`].
+    pub fn from_common(common: CommonResolutions) -> Self {
+        Resolution::from(common)
+    }
+
+    /// Creates a resolution from one of the 4:3 [`common4x3::CommonResolutions`].
+    pub fn from_common_4x3(common: common4x3::CommonResolutions) -> Self {
+        Resolution::from(common)
+    }
+
+    /// Creates a resolution from the window's current logical size.
+    #[cfg(feature = "bevy_window")]
+    pub fn from_window(window: &bevy_window::Window) -> Self {
+        Resolution::new(window.width(), window.height())
+    }
+
+    /// Creates a resolution from the window's current physical size.
+    #[cfg(feature = "bevy_window")]
+    pub fn from_window_physical(window: &bevy_window::Window) -> Self {
+        Resolution::new(
+            window.physical_width() as f32,
+            window.physical_height() as f32,
+        )
+    }
+
+    /// Creates a resolution from the window's current logical size, alongside the window's scale
+    /// factor.
+    #[cfg(feature = "bevy_window")]
+    pub fn from_window_scaled(window: &bevy_window::Window) -> (Self, f32) {
+        (Resolution::from_window(window), window.scale_factor())
+    }
+
+    /// Converts the resolution into a [`bevy_window::WindowResolution`], rounding each dimension up
+    /// to whole physical pixels.
+    ///
+    /// The aspect ratio mode and any fractional part of the dimensions can't be represented by a
+    /// `WindowResolution` and are lost, only the rounded dimensions survive a round trip through
+    /// [`Resolution::from_window_resolution`].
+    #[cfg(feature = "bevy_window")]
+    pub fn to_window_resolution(&self) -> bevy_window::WindowResolution {
+        bevy_window::WindowResolution::from(UVec2::from(*self))
+    }
+
+    /// Creates a dynamic resolution from the physical size of a [`bevy_window::WindowResolution`].
+    #[cfg(feature = "bevy_window")]
+    pub fn from_window_resolution(resolution: &bevy_window::WindowResolution) -> Self {
+        Resolution::new(
+            resolution.physical_width() as f32,
+            resolution.physical_height() as f32,
+        )
+    }
+
+    /// Creates a camera [`bevy_camera::Viewport`] of this size at the given physical position.
+    #[cfg(feature = "bevy_camera")]
+    pub fn to_viewport(&self, position: UVec2) -> bevy_camera::Viewport {
+        bevy_camera::Viewport {
+            physical_position: position,
+            physical_size: UVec2::from(*self),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a [`bevy_camera::ScalingMode::Fixed`] that always shows exactly this resolution's
+    /// area of the world, stretching it to the viewport.
+    #[cfg(feature = "bevy_camera")]
+    pub fn to_scaling_mode_fixed(&self) -> bevy_camera::ScalingMode {
+        bevy_camera::ScalingMode::Fixed {
+            width: self.width,
+            height: self.height,
+        }
+    }
+
+    /// Creates a [`bevy_camera::ScalingMode`] that keeps the viewport's aspect ratio while showing
+    /// this resolution's area of the world.
+    ///
+    /// With `crop` unset this is [`bevy_camera::ScalingMode::AutoMin`], which always shows the whole
+    /// area and reveals more of the world on one axis when the aspect ratios differ. With `crop` set
+    /// this is [`bevy_camera::ScalingMode::AutoMax`], which fills the viewport and cuts off part of
+    /// the area instead.
+    #[cfg(feature = "bevy_camera")]
+    pub fn to_scaling_mode_auto(&self, crop: bool) -> bevy_camera::ScalingMode {
+        if crop {
+            bevy_camera::ScalingMode::AutoMax {
+                max_width: self.width,
+                max_height: self.height,
+            }
+        } else {
+            bevy_camera::ScalingMode::AutoMin {
+                min_width: self.width,
+                min_height: self.height,
+            }
+        }
+    }
+
+    /// Converts the resolution into a texture [`bevy_render::render_resource::Extent3d`] with the given
+    /// depth or array layer count, rounding up to whole pixels.
+    #[cfg(feature = "bevy_render")]
+    pub fn to_extent(&self, depth_or_array_layers: u32) -> bevy_render::render_resource::Extent3d {
+        let size = UVec2::from(*self);
+        bevy_render::render_resource::Extent3d {
+            width: size.x,
+            height: size.y,
+            depth_or_array_layers,
+        }
+    }
+
+    /// Whether both dimensions, rounded up to whole pixels, are within the `wgpu` limit for 2D
+    /// textures.
+    #[cfg(feature = "bevy_render")]
+    pub fn fits_limits(&self, limits: &bevy_render::settings::WgpuLimits) -> bool {
+        let size = UVec2::from(*self);
+        size.max_element() <= limits.max_texture_dimension_2d
+    }
+
+    /// Uniformly scales the resolution down to whole pixels that fit within the `wgpu` limit for 2D
+    /// textures, preserving the aspect ratio. Resolutions that already fit are returned as is.
+    #[cfg(feature = "bevy_render")]
+    pub fn clamp_to_limits(self, limits: &bevy_render::settings::WgpuLimits) -> Self {
+        if self.fits_limits(limits) {
+            return self;
+        }
+
+        self.scale_uniform(limits.max_texture_dimension_2d as f32 / self.longest_side())
+            .floor()
+    }
+
+    /// Returns the aspect ratio, computing it from the dimensions if it is dynamic.
+    ///
+    /// A dynamic resolution with whole pixel dimensions reports the same ratio as the matching
+    /// constant, e.g. 1920x1080 is exactly [`AspectRatio::SIXTEEN_NINE`].
+    ///
+    /// Panics if the aspect ratio is dynamic and either dimension is zero, infinite, or NaN, see
+    /// [`Resolution::aspect_ratio_checked`] for a non-panicking version. The panic reports the
+    /// caller's location, but methods that use the aspect ratio internally, such as
+    /// [`Resolution::is_approx_ratio`] or [`Resolution::hor_plus`], report their own.
+    #[track_caller]
     pub fn aspect_ratio(&self) -> AspectRatio {
+        match self.aspect_ratio_checked() {
+            Ok(ar) => ar,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Returns the aspect ratio, computing it from the dimensions if it is dynamic, or an error if
+    /// the dimensions can't form one.
+    pub fn aspect_ratio_checked(&self) -> Result<AspectRatio, ResolutionError> {
         match self.aspect_ratio {
-            AspectRatioMode::Dynamic => AspectRatio::try_new(self.width, self.height).unwrap(),
-            AspectRatioMode::Set(ar) => ar,
+            AspectRatioMode::Dynamic => {
+                AspectRatio::try_new(self.width, self.height).map_err(|_| {
+                    ResolutionError::InvalidAspectRatio {
+                        width: self.width,
+                        height: self.height,
+                    }
+                })
+            }
+            AspectRatioMode::Set(ar) => Ok(ar),
+        }
+    }
+
+    /// Whether this resolution's aspect ratio is within `tolerance` of `target`.
+    pub fn is_approx_ratio(&self, target: &AspectRatio, tolerance: f32) -> bool {
+        (self.aspect_ratio().ratio() - target.ratio()).abs() <= tolerance
+    }
+
+    /// Sets the aspect ratio to the [`Resolution::nearest_named_ratio`] and recomputes the width from
+    /// the height, if the current ratio is within `tolerance` of it. Otherwise the resolution is
+    /// returned unchanged.
+    pub fn aspect_snapped(self, tolerance: f32) -> Self {
+        let nearest = self.nearest_named_ratio();
+
+        if self.is_approx_ratio(&nearest, tolerance) {
+            self.change_ratio(nearest)
+        } else {
+            self
+        }
+    }
+
+    /// Returns the commonly named aspect ratio (1:1, 4:3, 16:10, 16:9, 21:9, or 32:9) closest to
+    /// this resolution's aspect ratio.
+    pub fn nearest_named_ratio(&self) -> AspectRatio {
+        let ratio = self.aspect_ratio().ratio();
+
+        NAMED_RATIOS
+            .iter()
+            .map(|named| named.ratio)
+            .min_by(|a, b| {
+                (a.ratio() - ratio)
+                    .abs()
+                    .total_cmp(&(b.ratio() - ratio).abs())
+            })
+            .unwrap()
+    }
+
+    /// Returns the common name of this resolution's aspect ratio, such as `"16:9"`, if it matches
+    /// one of the commonly named aspect ratios.
+    pub fn aspect_ratio_name(&self) -> Option<&'static str> {
+        let ratio = self.aspect_ratio().ratio();
+
+        NAMED_RATIOS
+            .iter()
+            .find(|named| approx_eq(named.ratio.ratio(), ratio))
+            .map(|named| named.name)
+    }
+
+    /// Returns how much, as a percentage, the aspect ratio would be distorted by stretching this
+    /// resolution to the dimensions of `target`.
+    pub fn distortion_percent_to(&self, target: &Resolution) -> f32 {
+        let ratio = self.aspect_ratio().ratio();
+
+        (target.aspect_ratio().ratio() - ratio).abs() / ratio * 100.
+    }
+
+    /// Returns the aspect ratio as a `width:height` pair of integers, such as `(16, 9)`.
+    ///
+    /// Commonly named aspect ratios keep their conventional form (16:10 rather than 8:5), otherwise
+    /// the smallest pair is used. Returns `None` if no reasonably small pair exactly matches.
+    pub fn simplified_ratio(&self) -> Option<(u32, u32)> {
+        simplify_ratio(self.aspect_ratio().ratio())
+    }
+
+    /// Returns the aspect ratio rounded to `decimals` decimal places, such as `1.778` for 16:9 at
+    /// 3 decimal places.
+    pub fn aspect_ratio_decimal(&self, decimals: u32) -> f32 {
+        let scale = 10f64.powi(decimals as i32);
+        ((self.aspect_ratio().ratio() as f64 * scale).round() / scale) as f32
+    }
+
+    /// Returns the aspect ratio as a [`Vec2`] of its [`Resolution::simplified_ratio`], such as
+    /// `(16., 9.)`, or `(ratio, 1.)` if the ratio can't be simplified.
+    pub fn aspect_ratio_vec(&self) -> Vec2 {
+        match self.simplified_ratio() {
+            Some((width, height)) => Vec2::new(width as f32, height as f32),
+            None => Vec2::new(self.aspect_ratio().ratio(), 1.),
         }
     }
 
@@ -65,6 +506,32 @@ impl Resolution {
         self.width
     }
 
+    /// Whether either dimension is not a whole number of pixels.
+    pub fn is_fractional(&self) -> bool {
+        !approx_eq(self.width, self.width.round()) || !approx_eq(self.height, self.height.round())
+    }
+
+    /// Returns the larger of the width and height.
+    pub fn longest_side(&self) -> f32 {
+        self.width.max(self.height)
+    }
+
+    /// Returns the smaller of the width and height.
+    pub fn shortest_side(&self) -> f32 {
+        self.width.min(self.height)
+    }
+
+    /// Returns the smallest square resolution that contains this one.
+    pub fn square_bound(&self) -> Self {
+        Resolution::new(self.longest_side(), self.longest_side())
+    }
+
+    /// Whether the two resolutions share an aspect ratio within a small tolerance, the precondition
+    /// for integer scaling between them.
+    pub fn aspect_compatible_with(&self, other: &Resolution) -> bool {
+        approx_eq(self.aspect_ratio().ratio(), other.aspect_ratio().ratio())
+    }
+
     pub fn has_integer_scale(&self, target_resolution: &Resolution) -> bool {
         has_integer_scale(self, target_resolution)
     }
@@ -73,60 +540,465 @@ impl Resolution {
         get_scale_factor(self, target_resolution)
     }
 
+    /// Returns the per-axis scale that changes only the width so that this resolution matches
+    /// `ratio` at its current height.
+    pub fn scale_factor_to_ratio(&self, ratio: AspectRatio) -> Vec2 {
+        Vec2::new(self.height * ratio.ratio() / self.width, 1.)
+    }
+
+    /// Returns the uniform factor that scales this resolution to `target`, or `None` if the width
+    /// and height would need to be scaled by different amounts.
+    pub fn uniform_scale_factor(&self, target: &Resolution) -> Option<f32> {
+        let x = target.width / self.width;
+        let y = target.height / self.height;
+
+        approx_eq(x, y).then_some(y)
+    }
+
+    /// Returns the Euclidean distance between the two resolutions in (width, height) space.
+    ///
+    /// See [`Resolution::area_distance`] for a metric based on pixel count instead.
+    pub fn distance(&self, other: &Resolution) -> f32 {
+        Vec2::from(*self).distance(Vec2::from(*other))
+    }
+
+    /// Returns the absolute difference in pixel area between the two resolutions.
+    pub fn area_distance(&self, other: &Resolution) -> f32 {
+        (self.area() - other.area()).abs()
+    }
+
+    /// Returns the candidate closest to this resolution by [`Resolution::distance`], or `None` if
+    /// there are no candidates.
+    pub fn nearest_from(&self, candidates: &[Resolution]) -> Option<Resolution> {
+        candidates
+            .iter()
+            .copied()
+            .min_by(|a, b| self.distance(a).total_cmp(&self.distance(b)))
+    }
+
+    /// Snaps this resolution onto the closest of `supported` by [`Resolution::distance`], preferring
+    /// to stay on `current` to avoid flapping between near-equal options.
+    ///
+    /// `current` is kept as long as it is one of `supported` and no more than `hysteresis` pixels
+    /// further away than the closest option. Returns this resolution unchanged if there are no
+    /// supported resolutions.
+    pub fn round_to_supported(
+        &self,
+        supported: &[Resolution],
+        current: Option<&Resolution>,
+        hysteresis: f32,
+    ) -> Self {
+        let Some(best) = self.nearest_from(supported) else {
+            return *self;
+        };
+
+        match current {
+            Some(current)
+                if supported.contains(current)
+                    && self.distance(current) <= self.distance(&best) + hysteresis =>
+            {
+                *current
+            }
+            _ => best,
+        }
+    }
+
+    /// Picks the monitor video mode closest to this resolution by [`Resolution::area_distance`],
+    /// for choosing an exclusive fullscreen mode. Modes with this resolution's aspect ratio are
+    /// preferred, the nearest mode overall is only returned when none match it.
+    ///
+    /// Returns `None` if there are no modes.
+    #[cfg(feature = "bevy_window")]
+    pub fn clamp_to_monitor_modes(&self, modes: &[Resolution]) -> Option<Resolution> {
+        let nearest = |modes: &mut dyn Iterator<Item = Resolution>| {
+            modes.min_by(|a, b| self.area_distance(a).total_cmp(&self.area_distance(b)))
+        };
+        let ratio = self.aspect_ratio();
+
+        nearest(
+            &mut modes
+                .iter()
+                .copied()
+                .filter(|mode| mode.is_approx_ratio(&ratio, EPSILON)),
+        )
+        .or_else(|| nearest(&mut modes.iter().copied()))
+    }
+
+    /// Snaps the width to the nearest of `widths` and the height to the nearest of `heights`
+    /// independently, with a dynamic aspect ratio.
+    ///
+    /// An axis is left unchanged if there are no values to snap it to, and ties go to the value
+    /// listed first.
+    pub fn snap_axes_to(&self, widths: &[f32], heights: &[f32]) -> Self {
+        let snap = |value: f32, options: &[f32]| {
+            options
+                .iter()
+                .copied()
+                .reduce(|best, option| {
+                    if (option - value).abs() < (best - value).abs() {
+                        option
+                    } else {
+                        best
+                    }
+                })
+                .unwrap_or(value)
+        };
+
+        Resolution::new(snap(self.width, widths), snap(self.height, heights))
+    }
+
+    /// Returns the largest of the [`CommonResolutions`] with no more pixels than this resolution, or
+    /// `None` if this resolution is smaller than all of them.
+    pub fn snap_down_to_common(&self) -> Option<CommonResolutions> {
+        CommonResolutions::iter()
+            .filter(|common| Resolution::from(*common).area() <= self.area())
+            .last()
+    }
+
+    /// Returns the smallest of the [`CommonResolutions`] with no fewer pixels than this resolution,
+    /// or `None` if this resolution is larger than all of them.
+    pub fn snap_up_to_common(&self) -> Option<CommonResolutions> {
+        CommonResolutions::iter().find(|common| Resolution::from(*common).area() >= self.area())
+    }
+
+    /// Labels this resolution with the preset it matches, or [`ResolutionLabel::Custom`] if it
+    /// matches none of them.
+    ///
+    /// A preset matches when its whole pixel dimensions and aspect ratio are the same as this
+    /// resolution's. The 16:9 [`CommonResolutions`] are checked before the 4:3 ones.
+    pub fn label(&self) -> ResolutionLabel {
+        let matches = |preset: Resolution| {
+            UVec2::from(*self) == UVec2::from(preset)
+                && self.is_approx_ratio(&preset.aspect_ratio(), EPSILON)
+        };
+
+        if let Some(common) = CommonResolutions::iter().find(|common| matches((*common).into())) {
+            return ResolutionLabel::Preset(common);
+        }
+        if let Some(common) =
+            common4x3::CommonResolutions::iter().find(|common| matches((*common).into()))
+        {
+            return ResolutionLabel::Preset4x3(common);
+        }
+
+        ResolutionLabel::Custom(*self)
+    }
+
+    /// Returns the [`CommonResolutions`] whose whole pixel dimensions are each within
+    /// `pixel_tolerance` pixels of this resolution's, such as 1080p for a 1912x1072 window that
+    /// lost a few pixels to its borders. The closest one wins if several are within tolerance.
+    pub fn is_within_tolerance_of_common(&self, pixel_tolerance: u32) -> Option<CommonResolutions> {
+        let size = UVec2::from(*self);
+
+        CommonResolutions::iter()
+            .map(|common| (common, UVec2::from(common)))
+            .filter(|(_, common)| {
+                size.x.abs_diff(common.x) <= pixel_tolerance
+                    && size.y.abs_diff(common.y) <= pixel_tolerance
+            })
+            .min_by_key(|(_, common)| size.x.abs_diff(common.x) + size.y.abs_diff(common.y))
+            .map(|(common, _)| common)
+    }
+
+    /// Returns the smallest of the [`CommonResolutions`] that is at least as wide and as tall as
+    /// this resolution, or `None` if none of them can contain it.
+    pub fn bounding_common(&self) -> Option<CommonResolutions> {
+        CommonResolutions::iter().find(|common| self.fits_within(&Resolution::from(*common)))
+    }
+
+    /// Whether the two resolutions describe the same display, comparing their dimensions and
+    /// computed aspect ratios within a small tolerance.
+    ///
+    /// Unlike the derived [`PartialEq`], this ignores whether the aspect ratio is dynamic or set, so
+    /// a dynamic 1920x1080 is equivalent to `r1080p(AspectRatio::SIXTEEN_NINE)`.
+    pub fn equivalent(&self, other: &Resolution) -> bool {
+        let close = |a: f32, b: f32| (a - b).abs() <= EPSILON * a.abs().max(1.);
+
+        close(self.width, other.width)
+            && close(self.height, other.height)
+            && approx_eq(self.aspect_ratio().ratio(), other.aspect_ratio().ratio())
+    }
+
+    /// Whether this resolution has more pixels than `other`. Resolutions with equal areas are
+    /// neither larger nor smaller than each other.
+    pub fn is_larger_than(&self, other: &Resolution) -> bool {
+        self.area() > other.area()
+    }
+
+    /// Whether this resolution has fewer pixels than `other`. Resolutions with equal areas are
+    /// neither larger nor smaller than each other.
+    pub fn is_smaller_than(&self, other: &Resolution) -> bool {
+        self.area() < other.area()
+    }
+
+    /// Bins the resolution by pixel count into one of `<720p`, `720p-1080p`, `1080p-1440p`,
+    /// `1440p-4K`, or `4K+`, for aggregating resolutions in analytics.
+    ///
+    /// Each bucket includes its lower bound, so 1920x1080 is in `1080p-1440p`.
+    pub fn histogram_bucket(&self) -> &'static str {
+        const BUCKETS: [(f32, &str); 4] = [
+            (1280. * 720., "<720p"),
+            (1920. * 1080., "720p-1080p"),
+            (2560. * 1440., "1080p-1440p"),
+            (3840. * 2160., "1440p-4K"),
+        ];
+
+        BUCKETS
+            .iter()
+            .find(|(upper, _)| self.area() < *upper)
+            .map_or("4K+", |(_, bucket)| bucket)
+    }
+
+    /// Scores the resolution for picking a default display mode, favouring more pixels and an
+    /// aspect ratio close to `target_aspect`.
+    ///
+    /// The score is the pixel area scaled by the square of the smaller of the two ratios divided by
+    /// the larger, so a resolution at exactly `target_aspect` scores its full area.
+    pub fn sharpness_score(&self, target_aspect: AspectRatio) -> f32 {
+        let ratio = self.aspect_ratio().ratio();
+        let target = target_aspect.ratio();
+        let aspect_match = ratio.min(target) / ratio.max(target);
+
+        self.area() * aspect_match * aspect_match
+    }
+
+    /// Returns the total pixel count in millions of pixels.
+    pub fn megapixels(&self) -> f32 {
+        self.area() / 1_000_000.
+    }
+
+    fn area(&self) -> f32 {
+        self.width * self.height
+    }
+
     pub fn change_height(mut self, height: f32, maintain_aspect_ratio: bool) -> Self {
         if maintain_aspect_ratio {
-            if (self.width / height) != self.aspect_ratio().ratio() {
-                self.width = height * self.aspect_ratio().ratio()
-            }
+            self.width = height * self.aspect_ratio().ratio();
         } else {
             self.aspect_ratio = AspectRatioMode::Dynamic;
         }
         self.height = height;
 
-        self
+        self.assert_consistent()
     }
 
     pub fn change_width(mut self, width: f32, maintain_aspect_ratio: bool) -> Self {
         if maintain_aspect_ratio {
-            if (width / self.height) != self.aspect_ratio().ratio() {
-                self.height = width / self.aspect_ratio().ratio()
-            }
+            self.height = width / self.aspect_ratio().ratio();
         } else {
             self.aspect_ratio = AspectRatioMode::Dynamic;
         }
 
         self.width = width;
 
-        self
+        self.assert_consistent()
+    }
+
+    /// Corrects a proposed size, such as from dragging a window corner, back onto this resolution's
+    /// aspect ratio.
+    ///
+    /// Whichever axis changed more relative to its current size drives the resize, and the other
+    /// axis is recomputed from the aspect ratio. Ties are driven by the width.
+    pub fn resize_locked(&self, proposed: Vec2) -> Self {
+        let width_change = ((proposed.x - self.width) / self.width).abs();
+        let height_change = ((proposed.y - self.height) / self.height).abs();
+
+        if height_change > width_change {
+            self.change_height(proposed.y, true)
+        } else {
+            self.change_width(proposed.x, true)
+        }
     }
 
     pub fn change_ratio(mut self, ratio: AspectRatio) -> Self {
         self.aspect_ratio = AspectRatioMode::Set(ratio);
         self.width = self.height * ratio.ratio();
 
+        self.assert_consistent()
+    }
+
+    /// Adopts `other`'s aspect ratio mode, dynamic or set, while keeping this resolution's
+    /// dimensions.
+    ///
+    /// Nothing is recomputed, so adopting a set aspect ratio the dimensions don't match leaves the
+    /// resolution inconsistent, see [`Resolution::is_consistent`].
+    pub fn with_aspect_from(self, other: &Resolution) -> Self {
+        Self {
+            aspect_ratio: other.aspect_ratio,
+            ..self
+        }
+    }
+
+    /// Whether the dimensions match the set aspect ratio, within a small tolerance. Resolutions with
+    /// a dynamic aspect ratio are always consistent.
+    pub fn is_consistent(&self) -> bool {
+        match self.aspect_ratio {
+            AspectRatioMode::Dynamic => true,
+            AspectRatioMode::Set(ar) => {
+                (self.width - self.height * ar.ratio()).abs() <= EPSILON * self.width.abs().max(1.)
+            }
+        }
+    }
+
+    /// Checks, in debug builds, that a mutation left the dimensions consistent with the set aspect
+    /// ratio. Non-finite dimensions come from the caller's input rather than the mutation, so they
+    /// are not checked.
+    fn assert_consistent(self) -> Self {
+        debug_assert!(
+            !(self.width.is_finite() && self.height.is_finite()) || self.is_consistent(),
+            "{:?} does not match its aspect ratio",
+            self
+        );
         self
     }
 
+    /// Interpolates the aspect ratio toward `target` by `t`, clamped to `[0, 1]`, keeping the height
+    /// and setting the aspect ratio to the blended value.
+    pub fn blend_aspect(&self, target: AspectRatio, t: f32) -> Self {
+        let t = t.clamp(0., 1.);
+        let ratio = self.aspect_ratio().ratio() * (1. - t) + target.ratio() * t;
+
+        match AspectRatio::try_new(ratio, 1.) {
+            Ok(ratio) => self.change_ratio(ratio),
+            Err(_) => *self,
+        }
+    }
+
+    /// Linearly interpolates both dimensions toward `target` by `t`, clamped to `[0, 1]`. A set
+    /// aspect ratio is kept only if the result still matches it.
+    ///
+    /// See [`Resolution::interpolate_safe`] for interpolating from or to degenerate resolutions.
+    pub fn lerp(&self, target: &Resolution, t: f32) -> Self {
+        let t = t.clamp(0., 1.);
+
+        self.resized(
+            self.width * (1. - t) + target.width * t,
+            self.height * (1. - t) + target.height * t,
+        )
+    }
+
+    /// Interpolates both dimensions toward `target` by `t`, clamped to `[0, 1]`, with a dynamic
+    /// aspect ratio.
+    ///
+    /// Each dimension is kept between 1 pixel and [`f32::MAX`], so intermediate frames of an
+    /// animation never produce a resolution whose [`Resolution::aspect_ratio`] would panic, even
+    /// when interpolating from or to a degenerate resolution.
+    pub fn interpolate_safe(&self, target: &Resolution, t: f32) -> Self {
+        let t = t.clamp(0., 1.);
+        let lerp = |from: f32, to: f32| match from * (1. - t) + to * t {
+            value if value.is_nan() => 1.,
+            value => value.clamp(1., f32::MAX),
+        };
+
+        Resolution::new(
+            lerp(self.width, target.width),
+            lerp(self.height, target.height),
+        )
+    }
+
+    /// Changes the aspect ratio to `ratio_w:ratio_h`, failing if either component is zero,
+    /// negative, infinite, or NaN.
+    pub fn try_change_ratio(self, ratio_w: f32, ratio_h: f32) -> Result<Self, ResolutionError> {
+        let invalid = ResolutionError::InvalidAspectRatio {
+            width: ratio_w,
+            height: ratio_h,
+        };
+
+        if !(ratio_w.is_finite() && ratio_w > 0. && ratio_h.is_finite() && ratio_h > 0.) {
+            return Err(invalid);
+        }
+
+        let ratio = AspectRatio::try_new(ratio_w, ratio_h).map_err(|_| invalid)?;
+
+        Ok(self.change_ratio(ratio))
+    }
+
+    /// Returns the smallest resolution with the given aspect ratio that fully contains this one.
+    pub fn grow_to_ratio(&self, ratio: AspectRatio) -> Self {
+        if self.width / self.height < ratio.ratio() {
+            Resolution::from_height(self.height, ratio)
+        } else {
+            Resolution::from_width(self.width, ratio)
+        }
+    }
+
+    /// Bounds the aspect ratio to `[min, max]`, returning the largest region of this resolution at
+    /// the nearest bound if it falls outside of that range.
+    pub fn clamp_aspect_ratio(&self, min: AspectRatio, max: AspectRatio) -> Self {
+        let ratio = self.width / self.height;
+
+        if ratio > max.ratio() {
+            Resolution::from_height(self.height, max)
+        } else if ratio < min.ratio() {
+            Resolution::from_width(self.width, min)
+        } else {
+            *self
+        }
+    }
+
+    /// Returns a dynamic resolution with the larger of each dimension.
+    pub fn max(self, other: &Resolution) -> Self {
+        Resolution::new(self.width.max(other.width), self.height.max(other.height))
+    }
+
+    /// Returns a dynamic resolution with the smaller of each dimension.
+    pub fn min(self, other: &Resolution) -> Self {
+        Resolution::new(self.width.min(other.width), self.height.min(other.height))
+    }
+
+    /// Changes the aspect ratio while keeping the total pixel count the same.
+    pub fn resize_keeping_area(self, ratio: AspectRatio) -> Self {
+        let area = self.width * self.height;
+
+        Resolution::from_height((area / ratio.ratio()).sqrt(), ratio)
+    }
+
+    /// Keeps this design resolution's height and adjusts its width to the window's aspect ratio,
+    /// as with "Hor+" field of view scaling.
+    pub fn hor_plus(&self, window: &Resolution) -> Self {
+        Resolution::new(self.height * window.aspect_ratio().ratio(), self.height)
+    }
+
+    /// Keeps this design resolution's width and adjusts its height to the window's aspect ratio,
+    /// as with "Vert-" field of view scaling.
+    pub fn vert_minus(&self, window: &Resolution) -> Self {
+        Resolution::new(self.width, self.width / window.aspect_ratio().ratio())
+    }
+
     pub fn can_fit(&self, aspect_ratio: &AspectRatio) -> bool {
         resolution_fits_aspect_ratio(self, aspect_ratio)
     }
 
+    /// Scales the resolution, returning `None` if `scalar` would change the aspect ratio by more
+    /// than a small tolerance. See [`Resolution::try_scale_and_keep_aspect_ratio`].
     pub fn scale_and_keep_aspect_ratio(self, scalar: Vec2) -> Option<Self> {
-        if (self.width * scalar.x) / (self.height * scalar.y) != self.aspect_ratio().ratio() {
-            return None;
+        self.try_scale_and_keep_aspect_ratio(scalar).ok()
+    }
+
+    /// Scales the resolution, failing with [`ResolutionError::AspectRatioBroken`] if `scalar`
+    /// would change the aspect ratio.
+    pub fn try_scale_and_keep_aspect_ratio(self, scalar: Vec2) -> Result<Self, ResolutionError> {
+        let expected = self.aspect_ratio().ratio();
+        let got = (self.width * scalar.x) / (self.height * scalar.y);
+
+        if !approx_eq(expected, got) {
+            return Err(ResolutionError::AspectRatioBroken { expected, got });
         }
 
-        Some(Self {
+        Ok(Self {
             width: self.width * scalar.x,
             height: self.height * scalar.y,
             aspect_ratio: self.aspect_ratio,
         })
     }
 
+    /// Scales each dimension by the matching component of `scalar`. A set aspect ratio is kept as
+    /// long as the scaled dimensions still match it within a small tolerance, so scaling by `k` and
+    /// then by `1. / k` restores the original aspect ratio mode.
     pub fn scale(self, scalar: Vec2) -> Self {
         let ratio = (self.width * scalar.x) / (self.height * scalar.y);
 
-        if self.aspect_ratio.is_dynamic() || self.aspect_ratio().ratio() == ratio {
+        if self.aspect_ratio.is_dynamic() || approx_eq(self.aspect_ratio().ratio(), ratio) {
             Self {
                 width: self.width * scalar.x,
                 height: self.height * scalar.y,
@@ -140,33 +1012,643 @@ impl Resolution {
             }
         }
     }
-}
 
-pub fn has_integer_scale(to: &Resolution, from: &Resolution) -> bool {
-    if from.aspect_ratio().ratio() != to.aspect_ratio().ratio() {
-        return false;
-    }
+    /// Scales the resolution like [`Resolution::scale`], also returning whether a set aspect ratio
+    /// was dropped because `scalar` no longer matched it.
+    pub fn scale_reporting(self, scalar: Vec2) -> (Self, bool) {
+        let scaled = self.scale(scalar);
+        let dropped = !self.aspect_ratio.is_dynamic() && scaled.aspect_ratio.is_dynamic();
 
-    if from.height < to.height {
-        (to.height / from.height) % 1. == 0.
-    } else {
-        (from.height / to.height) % 1. == 0.
+        (scaled, dropped)
     }
-}
-pub fn get_scale_factor(to: &Resolution, from: &Resolution) -> Vec2 {
-    if from.aspect_ratio().ratio() != to.aspect_ratio().ratio() {
-        Vec2::new(from.width / to.width, from.height / to.height)
-    } else {
-        Vec2::splat(from.height / to.height)
+
+    /// Scales the width and height by separate integer factors. The aspect ratio becomes dynamic
+    /// unless both factors are the same.
+    pub fn scale_each(self, x: u32, y: u32) -> Self {
+        if x == y {
+            return self.scale_uniform(x as f32);
+        }
+
+        Resolution::new(self.width * x as f32, self.height * y as f32)
     }
-}
-pub fn resolution_fits_aspect_ratio(resolution: &Resolution, aspect_ratio: &AspectRatio) -> bool {
-    fits_aspect_ratio(resolution.height, aspect_ratio)
-}
 
-pub fn fits_aspect_ratio(height: f32, aspect_ratio: &AspectRatio) -> bool {
-    (height * aspect_ratio.ratio()) % 1. == 0.
-}
+    /// Scales the resolution like [`Resolution::scale`], but never lets either dimension drop below
+    /// 1 pixel.
+    pub fn saturating_scale(self, scalar: Vec2) -> Self {
+        let scaled = self.scale(scalar);
+
+        if scaled.width >= 1. && scaled.height >= 1. {
+            return scaled;
+        }
+
+        scaled.resized(scaled.width.max(1.), scaled.height.max(1.))
+    }
+
+    /// Halves the resolution, flooring the result to whole pixels.
+    ///
+    /// Neither dimension drops below 1 pixel, so repeatedly halving bottoms out at 1x1.
+    pub fn half(self) -> Self {
+        self.saturating_scale(Vec2::splat(0.5)).floor()
+    }
+
+    /// Divides the pixel dimensions by `divisor`, flooring the result, with a dynamic aspect ratio.
+    ///
+    /// Fractional dimensions are rounded up first, as with `UVec2::from`. Neither dimension drops
+    /// below 1 pixel. Panics if `divisor` is 0.
+    pub fn scale_down(self, divisor: u32) -> Self {
+        let size = (UVec2::from(self) / divisor).max(UVec2::ONE);
+
+        Resolution::new(size.x as f32, size.y as f32)
+    }
+
+    /// Doubles the resolution.
+    pub fn double(self) -> Self {
+        self.scale(Vec2::splat(2.))
+    }
+
+    /// Rounds each dimension, as whole pixels, to a power of two according to `mode`, with a dynamic
+    /// aspect ratio. Neither dimension drops below 1 pixel.
+    ///
+    /// Dimensions above 2^63 pixels have no larger power of two to round up to, so every mode
+    /// rounds them down to 2^63.
+    pub fn nearest_pow2(self, mode: RoundMode) -> Self {
+        let round = |value: f32| {
+            let value = (value.ceil() as u64).max(1);
+            let down = 1 << (u64::BITS - 1 - value.leading_zeros());
+            let up = value.checked_next_power_of_two().unwrap_or(down);
+
+            match mode {
+                RoundMode::Up => up,
+                RoundMode::Down => down,
+                RoundMode::Nearest if up.abs_diff(value) <= value.abs_diff(down) => up,
+                RoundMode::Nearest => down,
+            }
+        };
+
+        Resolution::new(round(self.width) as f32, round(self.height) as f32)
+    }
+
+    /// Returns the size of a single pixel in UV coordinates, `(1. / width, 1. / height)`, such as
+    /// for sampling offsets in post-processing shaders.
+    pub fn texel_size(&self) -> Vec2 {
+        Vec2::ONE / Vec2::from(*self)
+    }
+
+    /// Returns the reciprocal of each dimension, an alias for [`Resolution::texel_size`].
+    pub fn inverse(&self) -> Vec2 {
+        self.texel_size()
+    }
+
+    /// Converts a logical resolution into physical pixels for the given scale factor.
+    pub fn as_physical(&self, scale_factor: f32) -> Self {
+        self.scale_uniform(scale_factor)
+    }
+
+    /// Converts a physical resolution into logical pixels for the given scale factor.
+    pub fn as_logical(&self, scale_factor: f32) -> Self {
+        Self {
+            width: self.width / scale_factor,
+            height: self.height / scale_factor,
+            ..*self
+        }
+    }
+
+    /// Rounds each dimension down to a whole pixel.
+    ///
+    /// A set aspect ratio is kept only if the rounded dimensions still match it.
+    pub fn floor(self) -> Self {
+        self.resized(self.width.floor(), self.height.floor())
+    }
+
+    /// Rounds each dimension up to a whole pixel.
+    ///
+    /// A set aspect ratio is kept only if the rounded dimensions still match it.
+    pub fn ceil(self) -> Self {
+        self.resized(self.width.ceil(), self.height.ceil())
+    }
+
+    /// Rounds each dimension to the nearest whole pixel.
+    ///
+    /// A set aspect ratio is kept only if the rounded dimensions still match it.
+    pub fn round(self) -> Self {
+        self.resized(self.width.round(), self.height.round())
+    }
+
+    /// Rounds each dimension up to the next even whole pixel, as required by most video codecs.
+    pub fn ensure_even(self) -> Self {
+        let res = UVec2::from(self);
+
+        self.resized((res.x + res.x % 2) as f32, (res.y + res.y % 2) as f32)
+    }
+
+    /// Returns the origin and size, in source pixels, of the centered region that stays visible
+    /// when this resolution is scaled to cover `container`.
+    pub fn cover_rect(&self, container: &Resolution) -> (Vec2, Vec2) {
+        let factor = (container.width / self.width).max(container.height / self.height);
+        let size = Vec2::from(*container) / factor;
+
+        ((Vec2::from(*self) - size) / 2., size)
+    }
+
+    /// Returns the top-left offset that centers this resolution, unscaled, within `container`. The
+    /// offset is negative on any axis where this resolution is larger than `container`.
+    pub fn center_offset_in(&self, container: &Resolution) -> Vec2 {
+        (Vec2::from(*container) - Vec2::from(*self)) / 2.
+    }
+
+    /// Returns the position and size of the largest centered region with this resolution's aspect
+    /// ratio that fits within `container`.
+    pub fn viewport_rect(&self, container: &Resolution) -> (Vec2, Vec2) {
+        let (_, fitted) = self.scale_to_fit_detailed(container);
+        let size = Vec2::from(fitted);
+
+        ((Vec2::from(*container) - size) / 2., size)
+    }
+
+    /// Splits this resolution, rounded up to whole pixels, into a `cols` by `rows` grid of
+    /// physical viewports for split screen, returning each cell's position and size row by row.
+    ///
+    /// Cells are evenly sized, with any remainder pixels going to the last column and row so the
+    /// grid covers the whole resolution. Returns no cells if the grid is empty or has more columns
+    /// or rows than the resolution has pixels, as some cells would have no size.
+    pub fn grid_viewports(&self, cols: u32, rows: u32) -> Vec<(UVec2, UVec2)> {
+        let size = UVec2::from(*self);
+        if cols == 0 || rows == 0 || size.x < cols || size.y < rows {
+            return Vec::new();
+        }
+
+        let cell = size / UVec2::new(cols, rows);
+        let last = size - cell * UVec2::new(cols - 1, rows - 1);
+
+        (0..rows)
+            .flat_map(|row| (0..cols).map(move |col| UVec2::new(col, row)))
+            .map(|index| {
+                let position = cell * index;
+                let cell_size = UVec2::new(
+                    if index.x == cols - 1 { last.x } else { cell.x },
+                    if index.y == rows - 1 { last.y } else { cell.y },
+                );
+                (position, cell_size)
+            })
+            .collect()
+    }
+
+    /// Uniformly scales this resolution to the largest size that fits within `container`,
+    /// returning the scale factor used alongside the result.
+    ///
+    /// A 0x0 resolution can't be scaled to fit, so it is returned as is with a factor of 1.
+    pub fn scale_to_fit_detailed(&self, container: &Resolution) -> (f32, Resolution) {
+        let factor = (container.width / self.width).min(container.height / self.height);
+        if !factor.is_finite() {
+            return (1., *self);
+        }
+
+        (factor, self.scale_uniform(factor))
+    }
+
+    /// Formats the resolution as `WIDTHxHEIGHT@W:H`, or `WIDTHxHEIGHT` when the aspect ratio is
+    /// dynamic, so that it can be read back with [`Resolution::from_config_string`].
+    pub fn to_config_string(&self) -> String {
+        match self.aspect_ratio {
+            AspectRatioMode::Dynamic => format!("{}x{}", self.width, self.height),
+            AspectRatioMode::Set(ar) => match simplify_ratio(ar.ratio()) {
+                Some((w, h)) => format!("{}x{}@{}:{}", self.width, self.height, w, h),
+                None => format!("{}x{}@{}", self.width, self.height, ar.ratio()),
+            },
+        }
+    }
+
+    /// Parses a resolution written by [`Resolution::to_config_string`], preserving whether its
+    /// aspect ratio is dynamic or set.
+    pub fn from_config_string(s: &str) -> Result<Self, ResolutionParseError> {
+        let (dimensions, aspect_ratio) = match s.split_once('@') {
+            Some((dimensions, ratio)) => {
+                (dimensions, AspectRatioMode::Set(parse_aspect_ratio(ratio)?))
+            }
+            None => (s, AspectRatioMode::Dynamic),
+        };
+        let (width, height) = dimensions
+            .split_once('x')
+            .ok_or(ResolutionParseError::InvalidFormat)?;
+
+        Ok(Self {
+            width: parse_dimension(width)?,
+            height: parse_dimension(height)?,
+            aspect_ratio,
+        })
+    }
+
+    /// Encodes the resolution into 9 bytes, the width and height rounded up to whole pixels as
+    /// little-endian `u32`s followed by a tag for the aspect ratio mode.
+    ///
+    /// The tag can only represent dynamic aspect ratios, the commonly named ratios, and ratios that
+    /// match the encoded dimensions. Any other set aspect ratio, including a named ratio that no
+    /// longer matches once the dimensions are rounded, is replaced by the ratio of the encoded
+    /// dimensions so that the decoded resolution stays consistent.
+    pub fn to_bytes(&self) -> [u8; 9] {
+        let size = UVec2::from(*self);
+        let tag = match self.aspect_ratio {
+            AspectRatioMode::Dynamic => DYNAMIC_TAG,
+            AspectRatioMode::Set(ar) if !approx_eq(size.x as f32 / size.y as f32, ar.ratio()) => {
+                DIMENSIONS_TAG
+            }
+            AspectRatioMode::Set(ar) => ENCODED_RATIOS
+                .iter()
+                .position(|encoded| *encoded == ar)
+                .map_or(DIMENSIONS_TAG, |index| index as u8 + 1),
+        };
+
+        let mut bytes = [0; 9];
+        bytes[0..4].copy_from_slice(&size.x.to_le_bytes());
+        bytes[4..8].copy_from_slice(&size.y.to_le_bytes());
+        bytes[8] = tag;
+        bytes
+    }
+
+    /// Decodes a resolution encoded by [`Resolution::to_bytes`].
+    ///
+    /// Fails if there aren't exactly 9 bytes, the aspect ratio tag is unknown, or either dimension
+    /// is zero.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, ResolutionError> {
+        let bytes: &[u8; 9] = bytes
+            .try_into()
+            .map_err(|_| ResolutionError::InvalidEncoding)?;
+        let width = u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]);
+        let height = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]);
+
+        let dimensions = AspectRatio::try_from_pixels(width, height).map_err(|_| {
+            ResolutionError::InvalidAspectRatio {
+                width: width as f32,
+                height: height as f32,
+            }
+        })?;
+        let aspect_ratio = match bytes[8] {
+            DYNAMIC_TAG => AspectRatioMode::Dynamic,
+            DIMENSIONS_TAG => AspectRatioMode::Set(dimensions),
+            tag => match ENCODED_RATIOS.get(tag as usize - 1) {
+                Some(encoded) => AspectRatioMode::Set(*encoded),
+                None => return Err(ResolutionError::InvalidEncoding),
+            },
+        };
+
+        Ok(Self {
+            width: width as f32,
+            height: height as f32,
+            aspect_ratio,
+        })
+    }
+
+    /// Returns how many whole `tile`s fit along the width and height of this resolution.
+    ///
+    /// Returns `(0, 0)` if either tile dimension is zero, negative, infinite, or NaN.
+    pub fn tile_count(&self, tile: &Resolution) -> (u32, u32) {
+        let valid = |value: f32| value.is_finite() && value > 0.;
+        if !(valid(tile.width) && valid(tile.height)) {
+            return (0, 0);
+        }
+
+        (
+            (self.width / tile.width).floor() as u32,
+            (self.height / tile.height).floor() as u32,
+        )
+    }
+
+    /// Returns how many whole `tile`s fit within this resolution in total, see
+    /// [`Resolution::tile_count`].
+    pub fn total_tiles(&self, tile: &Resolution) -> u64 {
+        let (columns, rows) = self.tile_count(tile);
+        columns as u64 * rows as u64
+    }
+
+    /// Returns every integer multiple of this resolution that still fits within `cap`, starting at
+    /// 1x.
+    pub fn steps_to(&self, cap: &Resolution) -> Vec<Resolution> {
+        self.integer_scales_within(cap)
+            .into_iter()
+            .map(|(_, step)| step)
+            .collect()
+    }
+
+    /// Returns every integer scale factor of this resolution that still fits within `window`,
+    /// paired with the scaled resolution.
+    ///
+    /// Resolutions with a dimension that is zero, negative, or NaN have no scales.
+    pub fn integer_scales_within(&self, window: &Resolution) -> Vec<(u32, Resolution)> {
+        if !(self.width > 0. && self.height > 0.) {
+            return Vec::new();
+        }
+
+        // One past the largest fitting factor, the tolerance in `fits_within` decides the last one.
+        let max_factor = (window.width / self.width).min(window.height / self.height) + 1.;
+
+        (1..=max_factor as u32)
+            .map(|factor| (factor, self.scale_uniform(factor as f32)))
+            .take_while(|(_, step)| step.fits_within(window))
+            .collect()
+    }
+
+    /// Iterates through the common 100%, 125%, 150%, and 200% scales of this resolution, rounded to
+    /// whole pixels.
+    pub fn iter_common_scales(&self) -> impl Iterator<Item = (f32, Resolution)> {
+        let res = *self;
+
+        [1., 1.25, 1.5, 2.].into_iter().map(move |factor| {
+            let scaled = res.scale_uniform(factor);
+
+            (
+                factor,
+                scaled.resized(scaled.width.round(), scaled.height.round()),
+            )
+        })
+    }
+
+    /// Uniformly scales the resolution by `factor`, keeping the result within `min` and `max` while
+    /// preserving the aspect ratio. `min` takes priority if the bounds conflict.
+    pub fn scale_clamped(self, factor: f32, min: &Resolution, max: &Resolution) -> Self {
+        self.scale_uniform(factor).clamp_uniform(min, max)
+    }
+
+    /// Returns the largest resolution with this resolution's aspect ratio that fits within
+    /// `window`, clamped between `min` and `max` while preserving the aspect ratio. `min` takes
+    /// priority if the bounds conflict.
+    pub fn responsive_fit(&self, window: &Resolution, min: &Resolution, max: &Resolution) -> Self {
+        let (_, fitted) = self.scale_to_fit_detailed(window);
+        fitted.clamp_uniform(min, max)
+    }
+
+    /// Uniformly scales down to fit within `max`, then up to cover `min`.
+    fn clamp_uniform(self, min: &Resolution, max: &Resolution) -> Self {
+        let shrink = (max.width / self.width).min(max.height / self.height);
+        let clamped = if shrink < 1. {
+            self.scale_uniform(shrink)
+        } else {
+            self
+        };
+
+        clamped.with_min_size(min)
+    }
+
+    /// Uniformly scales the resolution up so that both dimensions are at least as large as `min`'s,
+    /// preserving the aspect ratio. Resolutions that already meet the minimum are returned as is.
+    ///
+    /// A resolution without a positive width and height can't be scaled up, so `min` is returned
+    /// instead.
+    pub fn with_min_size(self, min: &Resolution) -> Self {
+        if !(self.width > 0. && self.height > 0.) {
+            return *min;
+        }
+
+        let grow = (min.width / self.width).max(min.height / self.height);
+        if grow > 1. {
+            self.scale_uniform(grow)
+        } else {
+            self
+        }
+    }
+
+    /// Uniformly scales the resolution down so that it has at most `max_mp` megapixels, preserving
+    /// the aspect ratio.
+    pub fn clamp_megapixels(self, max_mp: f32) -> Self {
+        self.clamp_area(max_mp * 1_000_000.)
+    }
+
+    /// Uniformly scales the resolution down so that it has at most `budget_pixels` pixels,
+    /// preserving the aspect ratio.
+    pub fn clamp_to_pixel_budget(self, budget_pixels: u64) -> Self {
+        self.clamp_area(budget_pixels as f32)
+    }
+
+    /// Uniformly scales down so that the area is at most `max_area`.
+    fn clamp_area(self, max_area: f32) -> Self {
+        if self.area() <= max_area {
+            return self;
+        }
+
+        self.scale_uniform((max_area / self.area()).sqrt())
+    }
+
+    /// Scales both dimensions by `factor`, keeping the aspect ratio mode.
+    fn scale_uniform(&self, factor: f32) -> Self {
+        Self {
+            width: self.width * factor,
+            height: self.height * factor,
+            ..*self
+        }
+    }
+
+    /// Whether both dimensions are no larger than `other`'s.
+    fn fits_within(&self, other: &Resolution) -> bool {
+        self.width <= other.width + EPSILON && self.height <= other.height + EPSILON
+    }
+
+    /// Formats the resolution like [`Display`], but without spaces, such as `1920x1080`.
+    pub fn to_string_compact(&self) -> String {
+        let res = UVec2::from(*self);
+        format!("{}x{}", res.x, res.y)
+    }
+
+    /// Returns a stable key identifying this resolution, such as `1920x1080:16:9` or
+    /// `1920x1080:dyn`, suitable for caching.
+    ///
+    /// Unlike [`Display`], the key includes the aspect ratio mode. Dimensions are rounded up to
+    /// whole pixels, and a set aspect ratio that can't be reduced to a fraction is encoded by its
+    /// bits.
+    pub fn to_key_string(&self) -> String {
+        let res = UVec2::from(*self);
+
+        match self.aspect_ratio {
+            AspectRatioMode::Dynamic => format!("{}x{}:dyn", res.x, res.y),
+            AspectRatioMode::Set(ar) => match simplify_ratio(ar.ratio()) {
+                Some((w, h)) => format!("{}x{}:{}:{}", res.x, res.y, w, h),
+                None => format!("{}x{}:{:08x}", res.x, res.y, ar.ratio().to_bits()),
+            },
+        }
+    }
+
+    /// Replaces the dimensions, keeping a set aspect ratio only if the new dimensions still match it.
+    fn resized(self, width: f32, height: f32) -> Self {
+        let aspect_ratio = match self.aspect_ratio {
+            AspectRatioMode::Set(ar) if approx_eq(width / height, ar.ratio()) => self.aspect_ratio,
+            _ => AspectRatioMode::Dynamic,
+        };
+
+        Self {
+            width,
+            height,
+            aspect_ratio,
+        }
+    }
+}
+
+/// How [`Resolution::nearest_pow2`] rounds each dimension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundMode {
+    /// Round up to the next power of two
+    Up,
+    /// Round down to the previous power of two
+    Down,
+    /// Round to the closest power of two, rounding up on ties
+    Nearest,
+}
+
+/// A [`Resolution`] labelled with the preset it matches, as returned by [`Resolution::label`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ResolutionLabel {
+    /// One of the 16:9 [`CommonResolutions`].
+    Preset(CommonResolutions),
+    /// One of the 4:3 [`common4x3::CommonResolutions`].
+    Preset4x3(common4x3::CommonResolutions),
+    /// A resolution that matches none of the presets.
+    Custom(Resolution),
+}
+
+/// A [`Resolution`] paired with its precomputed aspect ratio.
+///
+/// [`Resolution::aspect_ratio`] recomputes the ratio of dynamic resolutions on every call, this
+/// computes it once up front for resolutions that are queried in tight loops. The cached ratio is
+/// recomputed whenever the resolution is replaced through [`CachedResolution::set`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CachedResolution {
+    resolution: Resolution,
+    aspect_ratio: AspectRatio,
+}
+
+impl CachedResolution {
+    pub fn new(resolution: Resolution) -> Self {
+        CachedResolution {
+            resolution,
+            aspect_ratio: resolution.aspect_ratio(),
+        }
+    }
+
+    pub fn resolution(&self) -> Resolution {
+        self.resolution
+    }
+
+    pub fn aspect_ratio(&self) -> AspectRatio {
+        self.aspect_ratio
+    }
+
+    /// Replaces the resolution and recomputes the cached aspect ratio.
+    pub fn set(&mut self, resolution: Resolution) {
+        *self = CachedResolution::new(resolution);
+    }
+}
+
+impl From<Resolution> for CachedResolution {
+    fn from(value: Resolution) -> Self {
+        CachedResolution::new(value)
+    }
+}
+impl From<CachedResolution> for Resolution {
+    fn from(value: CachedResolution) -> Self {
+        value.resolution
+    }
+}
+
+pub fn has_integer_scale(to: &Resolution, from: &Resolution) -> bool {
+    if from.aspect_ratio().ratio() != to.aspect_ratio().ratio() {
+        return false;
+    }
+
+    if from.height < to.height {
+        (to.height / from.height) % 1. == 0.
+    } else {
+        (from.height / to.height) % 1. == 0.
+    }
+}
+pub fn get_scale_factor(to: &Resolution, from: &Resolution) -> Vec2 {
+    if from.aspect_ratio().ratio() != to.aspect_ratio().ratio() {
+        Vec2::new(from.width / to.width, from.height / to.height)
+    } else {
+        Vec2::splat(from.height / to.height)
+    }
+}
+/// Returns the largest resolution within `cap` that every one of `bases` can integer scale to, or
+/// `None` if there is no such resolution.
+pub fn common_integer_target(bases: &[Resolution], cap: &Resolution) -> Option<Resolution> {
+    fn lcm(a: u64, b: u64) -> u64 {
+        a / gcd(a, b) * b
+    }
+
+    let sizes = bases
+        .iter()
+        .map(|base| UVec2::from(*base))
+        .collect::<Vec<UVec2>>();
+    if sizes.is_empty() || sizes.iter().any(|size| size.x == 0 || size.y == 0) {
+        return None;
+    }
+
+    let width = sizes.iter().fold(1, |acc, size| lcm(acc, size.x as u64));
+    let height = sizes.iter().fold(1, |acc, size| lcm(acc, size.y as u64));
+    if sizes
+        .iter()
+        .any(|size| width / size.x as u64 != height / size.y as u64)
+    {
+        return None;
+    }
+
+    let cap = UVec2::from(*cap);
+    let multiple = (cap.x as u64 / width).min(cap.y as u64 / height);
+    if multiple == 0 {
+        return None;
+    }
+
+    Some(Resolution::new(
+        (width * multiple) as f32,
+        (height * multiple) as f32,
+    ))
+}
+
+/// Returns the smallest dynamic resolution that contains every one of `resolutions`, or `None` if
+/// there are none.
+pub fn bounding_union(resolutions: &[Resolution]) -> Option<Resolution> {
+    let (first, rest) = resolutions.split_first()?;
+
+    Some(rest.iter().fold(
+        Resolution::new(first.width, first.height),
+        |union, resolution| union.max(resolution),
+    ))
+}
+
+/// Converts each resolution into a [`UVec2`], rounding fractional dimensions up.
+pub fn to_uvec2_vec(resolutions: &[Resolution]) -> Vec<UVec2> {
+    resolutions.iter().map(|res| UVec2::from(*res)).collect()
+}
+
+/// Converts each resolution into a [`Vec2`].
+pub fn to_vec2_vec(resolutions: &[Resolution]) -> Vec<Vec2> {
+    resolutions.iter().map(|res| Vec2::from(*res)).collect()
+}
+
+/// Parses a comma-separated list of resolutions, such as `1920x1080,1280x720,640x360`.
+///
+/// Each entry is parsed with [`Resolution::from_str`], an empty string is an empty list.
+pub fn parse_list(s: &str) -> Result<Vec<Resolution>, ResolutionParseError> {
+    if s.trim().is_empty() {
+        return Ok(Vec::new());
+    }
+
+    s.split(',')
+        .enumerate()
+        .map(|(index, entry)| {
+            entry
+                .parse()
+                .map_err(|error| ResolutionParseError::InvalidListEntry {
+                    index,
+                    error: Box::new(error),
+                })
+        })
+        .collect()
+}
+
+pub fn resolution_fits_aspect_ratio(resolution: &Resolution, aspect_ratio: &AspectRatio) -> bool {
+    fits_aspect_ratio(resolution.height, aspect_ratio)
+}
+
+pub fn fits_aspect_ratio(height: f32, aspect_ratio: &AspectRatio) -> bool {
+    (height * aspect_ratio.ratio()) % 1. == 0.
+}
 
 pub fn r360p(aspect_ratio: AspectRatio) -> Resolution {
     Resolution::from_height(360., aspect_ratio)
@@ -194,6 +1676,16 @@ impl From<Resolution> for UVec2 {
         UVec2::new(value.width.ceil() as u32, value.height.ceil() as u32)
     }
 }
+impl From<(u32, u32)> for Resolution {
+    fn from((width, height): (u32, u32)) -> Self {
+        Resolution::new(width as f32, height as f32)
+    }
+}
+impl From<[u32; 2]> for Resolution {
+    fn from([width, height]: [u32; 2]) -> Self {
+        Resolution::new(width as f32, height as f32)
+    }
+}
 impl From<Resolution> for AspectRatio {
     fn from(value: Resolution) -> Self {
         value.aspect_ratio()
@@ -203,7 +1695,55 @@ impl From<Resolution> for AspectRatio {
 #[cfg(feature = "bevy_window")]
 impl From<Resolution> for bevy_window::WindowResolution {
     fn from(value: Resolution) -> Self {
-        bevy_window::WindowResolution::from(UVec2::from(value))
+        value.to_window_resolution()
+    }
+}
+
+/// Divides each dimension by the matching component of `divisor`, following the same aspect ratio
+/// rules as [`Resolution::scale`].
+///
+/// Panics if either component of `divisor` is zero.
+impl Div<Vec2> for Resolution {
+    type Output = Resolution;
+
+    fn div(self, divisor: Vec2) -> Self::Output {
+        assert!(
+            divisor.x != 0. && divisor.y != 0.,
+            "cannot divide a resolution by zero"
+        );
+
+        self.resized(self.width / divisor.x, self.height / divisor.y)
+    }
+}
+
+/// Divides both dimensions by `divisor`. Panics if `divisor` is zero.
+impl Div<f32> for Resolution {
+    type Output = Resolution;
+
+    fn div(self, divisor: f32) -> Self::Output {
+        self / Vec2::splat(divisor)
+    }
+}
+
+impl DivAssign<Vec2> for Resolution {
+    fn div_assign(&mut self, divisor: Vec2) {
+        *self = *self / divisor;
+    }
+}
+
+impl DivAssign<f32> for Resolution {
+    fn div_assign(&mut self, divisor: f32) {
+        *self = *self / divisor;
+    }
+}
+
+impl FromStr for Resolution {
+    type Err = ResolutionParseError;
+
+    /// Parses a resolution with [`Resolution::from_config_string`]. Dimensions may be fractional,
+    /// such as `426.67x240`, and are kept exactly as parsed.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Resolution::from_config_string(s)
     }
 }
 
@@ -214,186 +1754,1925 @@ impl Display for Resolution {
     }
 }
 
+/// Classic low base resolutions commonly used by pixel-art games.
+pub mod retro {
+    use crate::resolutions::Resolution;
+    use bevy_math::AspectRatio;
+
+    /// 320x180, the smallest common 16:9 pixel-art base
+    pub fn r180p() -> Resolution {
+        Resolution::from_height(180., AspectRatio::SIXTEEN_NINE)
+    }
+
+    /// 384x216, a 16:9 pixel-art base that integer-scales to 1080p and 4K
+    pub fn r216p() -> Resolution {
+        Resolution::from_height(216., AspectRatio::SIXTEEN_NINE)
+    }
+
+    /// 256x224, the NES and SNES output resolution
+    pub fn nes() -> Resolution {
+        Resolution::new(256., 224.)
+    }
+
+    /// 320x240, Quarter VGA
+    pub fn qvga() -> Resolution {
+        Resolution::from_height(240., AspectRatio::FOUR_THREE)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use bevy_math::UVec2;
+
+        #[test]
+        fn dimensions() {
+            assert_eq!(UVec2::from(r180p()), UVec2::new(320, 180));
+            assert_eq!(UVec2::from(r216p()), UVec2::new(384, 216));
+            assert_eq!(UVec2::from(nes()), UVec2::new(256, 224));
+            assert_eq!(UVec2::from(qvga()), UVec2::new(320, 240));
+        }
+
+        #[test]
+        fn aspect_ratios() {
+            assert_eq!(r180p().aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+            assert_eq!(r216p().aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+            assert_eq!(nes().aspect_ratio().ratio(), 8. / 7.);
+            assert_eq!(qvga().aspect_ratio(), AspectRatio::FOUR_THREE);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    /// Deterministic pseudo-random heights in `1.0..10_000.0`, from a xorshift generator.
+    fn sample_heights(count: usize) -> impl Iterator<Item = f32> {
+        let mut state = 0x2545_f491_u32;
+        std::iter::repeat_with(move || {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+            1. + (state as f32 / u32::MAX as f32) * 9_999.
+        })
+        .take(count)
+    }
+
     #[test]
-    fn can_fit() {
-        assert!(fits_aspect_ratio(360., &AspectRatio::FOUR_THREE));
-        assert!(fits_aspect_ratio(360., &AspectRatio::SIXTEEN_NINE));
-        assert!(!fits_aspect_ratio(480., &AspectRatio::SIXTEEN_NINE));
-        assert!(fits_aspect_ratio(480., &AspectRatio::FOUR_THREE));
+    fn aspect_ratio_checked() {
+        assert_eq!(
+            r1080p(AspectRatio::SIXTEEN_NINE).aspect_ratio_checked(),
+            Ok(AspectRatio::SIXTEEN_NINE)
+        );
+        assert_eq!(
+            Resolution::new(1366., 768.).aspect_ratio_checked(),
+            Ok(AspectRatio::try_from_pixels(1366, 768).unwrap())
+        );
+        assert!(Resolution::new(1., 1e18).aspect_ratio_checked().is_ok());
+        assert_eq!(
+            Resolution::new(1920., 0.).aspect_ratio_checked(),
+            Err(ResolutionError::InvalidAspectRatio {
+                width: 1920.,
+                height: 0.
+            })
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid aspect ratio: 1920:0")]
+    fn aspect_ratio_panics() {
+        Resolution::new(1920., 0.).aspect_ratio();
+    }
+
+    #[test]
+    fn aspect_snapped() {
+        let r768 = Resolution::new(1366., 768.);
+        let snapped = r768.aspect_snapped(0.01);
+        let r1000x700 = Resolution::new(1000., 700.);
+
+        assert_eq!(
+            snapped,
+            Resolution::from_height(768., AspectRatio::SIXTEEN_NINE)
+        );
+        assert_eq!(snapped.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+        assert_eq!(r768.aspect_snapped(0.0001), r768);
+        assert_eq!(r1000x700.aspect_snapped(0.01), r1000x700);
+    }
+
+    #[test]
+    fn dynamic_named_ratios() {
+        assert_eq!(
+            Resolution::new(1920., 1080.).aspect_ratio(),
+            AspectRatio::SIXTEEN_NINE
+        );
+        assert_eq!(
+            Resolution::new(1024., 768.).aspect_ratio(),
+            AspectRatio::FOUR_THREE
+        );
+        assert_eq!(
+            Resolution::new(2520., 1080.).aspect_ratio(),
+            AspectRatio::ULTRAWIDE
+        );
+        assert_eq!(
+            Resolution::new(1680., 1050.).aspect_ratio(),
+            AspectRatio::try_from_pixels(16, 10).unwrap()
+        );
+        assert_eq!(
+            Resolution::new(1366., 768.).aspect_ratio(),
+            AspectRatio::try_from_pixels(1366, 768).unwrap()
+        );
+    }
+
+    #[test]
+    fn from_width_and_from_height_agree() {
+        let ratios = [
+            AspectRatio::try_new(1., 1.).unwrap(),
+            AspectRatio::FOUR_THREE,
+            AspectRatio::SIXTEEN_NINE,
+            AspectRatio::ULTRAWIDE,
+            AspectRatio::try_new(1.85, 1.).unwrap(),
+        ];
+
+        for ar in ratios {
+            for height in sample_heights(1_000) {
+                let from_height = Resolution::from_height(height, ar);
+                assert_eq!(from_height.width(), height * ar.ratio());
+
+                let from_width = Resolution::from_width(from_height.width(), ar);
+                assert!(
+                    (from_width.height() - height).abs() <= EPSILON * height,
+                    "{height} at {} came back as {}",
+                    ar.ratio(),
+                    from_width.height()
+                );
+                assert_eq!(from_width.aspect_ratio(), ar);
+            }
+        }
+    }
+
+    #[test]
+    fn can_fit() {
+        assert!(fits_aspect_ratio(360., &AspectRatio::FOUR_THREE));
+        assert!(fits_aspect_ratio(360., &AspectRatio::SIXTEEN_NINE));
+        assert!(!fits_aspect_ratio(480., &AspectRatio::SIXTEEN_NINE));
+        assert!(fits_aspect_ratio(480., &AspectRatio::FOUR_THREE));
+    }
+
+    #[test]
+    fn aspect_compatible_with() {
+        let r360 = r360p(AspectRatio::SIXTEEN_NINE);
+
+        assert!(r360.aspect_compatible_with(&r1080p(AspectRatio::SIXTEEN_NINE)));
+        assert!(r360.aspect_compatible_with(&Resolution::new(1920., 1080.)));
+        assert!(!r360.aspect_compatible_with(&r1080p(AspectRatio::FOUR_THREE)));
+        assert!(!r360.aspect_compatible_with(&Resolution::new(1366., 768.)));
+    }
+
+    #[test]
+    fn can_integer_scale() {
+        assert!(!has_integer_scale(
+            &r360p(AspectRatio::FOUR_THREE),
+            &r480p(AspectRatio::FOUR_THREE)
+        ));
+        assert!(has_integer_scale(
+            &r360p(AspectRatio::SIXTEEN_NINE),
+            &r720p(AspectRatio::SIXTEEN_NINE)
+        ));
+        assert!(!has_integer_scale(
+            &r360p(AspectRatio::SIXTEEN_NINE),
+            &r720p(AspectRatio::FOUR_THREE)
+        ));
+    }
+
+    #[test]
+    fn bounding_union() {
+        let tall = Resolution::new(600., 1200.);
+        let wide = Resolution::new(1600., 400.);
+
+        assert_eq!(
+            super::bounding_union(&[tall, wide]),
+            Some(Resolution::new(1600., 1200.))
+        );
+        assert_eq!(
+            super::bounding_union(&[r720p(AspectRatio::SIXTEEN_NINE)]),
+            Some(Resolution::new(1280., 720.))
+        );
+        assert_eq!(super::bounding_union(&[]), None);
+    }
+
+    #[test]
+    fn common_target() {
+        let r4k = Resolution::new(3840., 2160.);
+
+        assert_eq!(
+            common_integer_target(&[retro::r180p(), retro::r216p()], &r4k),
+            Some(Resolution::new(3840., 2160.))
+        );
+        assert_eq!(
+            common_integer_target(
+                &[retro::r180p(), retro::r216p()],
+                &r1440p(AspectRatio::SIXTEEN_NINE)
+            ),
+            Some(Resolution::new(1920., 1080.))
+        );
+        assert_eq!(
+            common_integer_target(&[retro::r180p(), retro::qvga()], &r4k),
+            None
+        );
+        assert_eq!(
+            common_integer_target(
+                &[retro::r180p(), retro::r216p()],
+                &r720p(AspectRatio::SIXTEEN_NINE)
+            ),
+            None
+        );
+        assert_eq!(common_integer_target(&[], &r4k), None);
+    }
+
+    #[test]
+    fn get_scale() {
+        assert_eq!(
+            get_scale_factor(
+                &r360p(AspectRatio::SIXTEEN_NINE),
+                &r720p(AspectRatio::SIXTEEN_NINE)
+            ),
+            Vec2::splat(2.)
+        );
+        assert_eq!(
+            get_scale_factor(
+                &r720p(AspectRatio::SIXTEEN_NINE),
+                &r360p(AspectRatio::SIXTEEN_NINE)
+            ),
+            Vec2::splat(0.5)
+        );
+    }
+
+    #[test]
+    fn is_fractional() {
+        assert!(Resolution::from_height(240., AspectRatio::SIXTEEN_NINE).is_fractional());
+        assert!(Resolution::new(1920., 1080.5).is_fractional());
+        assert!(!r1080p(AspectRatio::SIXTEEN_NINE).is_fractional());
+    }
+
+    #[test]
+    fn sides() {
+        let landscape = r1080p(AspectRatio::SIXTEEN_NINE);
+        let portrait = Resolution::new(1080., 1920.);
+
+        assert_eq!(landscape.longest_side(), 1920.);
+        assert_eq!(landscape.shortest_side(), 1080.);
+        assert_eq!(portrait.longest_side(), 1920.);
+        assert_eq!(portrait.shortest_side(), 1080.);
+        assert_eq!(landscape.square_bound(), Resolution::new(1920., 1920.));
+        assert_eq!(portrait.square_bound(), Resolution::new(1920., 1920.));
+    }
+
+    #[test]
+    fn scale_factor_to_ratio() {
+        let r1080_4x3 = r1080p(AspectRatio::FOUR_THREE);
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        let widen = r1080_4x3.scale_factor_to_ratio(AspectRatio::SIXTEEN_NINE);
+        assert!(approx_eq(widen.x, 4. / 3.));
+        assert_eq!(widen.y, 1.);
+        assert_eq!(
+            r1080_4x3.scale(widen).aspect_ratio(),
+            AspectRatio::SIXTEEN_NINE
+        );
+
+        let narrow = r1080.scale_factor_to_ratio(AspectRatio::FOUR_THREE);
+        assert!(approx_eq(narrow.x, 0.75));
+        assert_eq!(narrow.y, 1.);
+    }
+
+    #[test]
+    fn uniform_scale_factor() {
+        let r360 = r360p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(
+            r360.uniform_scale_factor(&r1080p(AspectRatio::SIXTEEN_NINE)),
+            Some(3.)
+        );
+        assert_eq!(
+            r360.uniform_scale_factor(&r1080p(AspectRatio::FOUR_THREE)),
+            None
+        );
+    }
+
+    #[test]
+    fn distance() {
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(r720.distance(&r720), 0.);
+        assert_eq!(r720.distance(&r1080), r1080.distance(&r720));
+        assert_eq!(r720.distance(&Resolution::new(1280., 1020.)), 300.);
+
+        assert_eq!(r720.area_distance(&r720), 0.);
+        assert_eq!(r720.area_distance(&r1080), r1080.area_distance(&r720));
+        assert_eq!(r720.area_distance(&r1080), 1152000.);
+    }
+
+    #[test]
+    fn nearest_from() {
+        let candidates = [
+            r720p(AspectRatio::SIXTEEN_NINE),
+            r1080p(AspectRatio::SIXTEEN_NINE),
+            r1440p(AspectRatio::SIXTEEN_NINE),
+        ];
+
+        assert_eq!(
+            Resolution::new(1800., 1000.).nearest_from(&candidates),
+            Some(r1080p(AspectRatio::SIXTEEN_NINE))
+        );
+        assert_eq!(
+            Resolution::new(100., 100.).nearest_from(&candidates),
+            Some(r720p(AspectRatio::SIXTEEN_NINE))
+        );
+        assert_eq!(Resolution::new(100., 100.).nearest_from(&[]), None);
+    }
+
+    #[test]
+    fn snap_axes_to() {
+        let widths = [800., 1024., 1280.];
+        let heights = [600., 720., 768.];
+
+        assert_eq!(
+            Resolution::new(1000., 650.).snap_axes_to(&widths, &heights),
+            Resolution::new(1024., 600.)
+        );
+        assert_eq!(
+            Resolution::new(1200., 740.).snap_axes_to(&widths, &heights),
+            Resolution::new(1280., 720.)
+        );
+        assert_eq!(
+            r720p(AspectRatio::SIXTEEN_NINE).snap_axes_to(&[], &heights),
+            Resolution::new(1280., 720.)
+        );
+    }
+
+    #[test]
+    fn snap_to_common() {
+        let r900 = Resolution::new(1600., 900.);
+        let r563 = Resolution::new(1000., 563.);
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(r900.snap_down_to_common(), Some(CommonResolutions::R720p));
+        assert_eq!(r900.snap_up_to_common(), Some(CommonResolutions::R1080p));
+        assert_eq!(r563.snap_down_to_common(), Some(CommonResolutions::R360p));
+        assert_eq!(r563.snap_up_to_common(), Some(CommonResolutions::R720p));
+        assert_eq!(r1080.snap_down_to_common(), Some(CommonResolutions::R1080p));
+        assert_eq!(r1080.snap_up_to_common(), Some(CommonResolutions::R1080p));
+
+        assert_eq!(Resolution::new(320., 180.).snap_down_to_common(), None);
+        assert_eq!(Resolution::new(3840., 2160.).snap_up_to_common(), None);
+    }
+
+    #[test]
+    fn label() {
+        let r480_4x3 = r480p(AspectRatio::FOUR_THREE);
+        let r800x600 = Resolution::new(800., 600.);
+        let r1080_4x3 = r1080p(AspectRatio::FOUR_THREE);
+
+        assert_eq!(
+            r720p(AspectRatio::SIXTEEN_NINE).label(),
+            ResolutionLabel::Preset(CommonResolutions::R720p)
+        );
+        assert_eq!(
+            Resolution::new(1280., 720.).label(),
+            ResolutionLabel::Preset(CommonResolutions::R720p)
+        );
+        assert_eq!(
+            r480_4x3.label(),
+            ResolutionLabel::Preset4x3(common4x3::CommonResolutions::R480p)
+        );
+        assert_eq!(r800x600.label(), ResolutionLabel::Custom(r800x600));
+        assert_eq!(r1080_4x3.label(), ResolutionLabel::Custom(r1080_4x3));
+    }
+
+    #[test]
+    fn histogram_bucket() {
+        assert_eq!(r480p(AspectRatio::FOUR_THREE).histogram_bucket(), "<720p");
+        assert_eq!(
+            Resolution::new(1366., 768.).histogram_bucket(),
+            "720p-1080p"
+        );
+        assert_eq!(
+            r1080p(AspectRatio::SIXTEEN_NINE).histogram_bucket(),
+            "1080p-1440p"
+        );
+        assert_eq!(Resolution::new(3440., 1440.).histogram_bucket(), "1440p-4K");
+        assert_eq!(Resolution::new(3840., 2160.).histogram_bucket(), "4K+");
+        assert_eq!(Resolution::new(7680., 4320.).histogram_bucket(), "4K+");
+    }
+
+    #[cfg(feature = "bevy_window")]
+    #[test]
+    fn clamp_to_monitor_modes() {
+        let modes = [
+            Resolution::new(1024., 768.),
+            Resolution::new(1280., 720.),
+            Resolution::new(1280., 1024.),
+            Resolution::new(1920., 1080.),
+        ];
+
+        assert_eq!(
+            Resolution::new(1600., 900.).clamp_to_monitor_modes(&modes),
+            Some(Resolution::new(1280., 720.))
+        );
+        assert_eq!(
+            Resolution::new(1152., 864.).clamp_to_monitor_modes(&modes),
+            Some(Resolution::new(1024., 768.))
+        );
+        assert_eq!(
+            Resolution::new(2560., 1080.).clamp_to_monitor_modes(&modes),
+            Some(Resolution::new(1920., 1080.))
+        );
+        assert_eq!(
+            Resolution::new(1600., 900.).clamp_to_monitor_modes(&[]),
+            None
+        );
+    }
+
+    #[test]
+    fn is_within_tolerance_of_common() {
+        let r1912 = Resolution::new(1912., 1072.);
+
+        assert_eq!(
+            r1912.is_within_tolerance_of_common(16),
+            Some(CommonResolutions::R1080p)
+        );
+        assert_eq!(r1912.is_within_tolerance_of_common(4), None);
+        assert_eq!(
+            r720p(AspectRatio::SIXTEEN_NINE).is_within_tolerance_of_common(0),
+            Some(CommonResolutions::R720p)
+        );
+        assert_eq!(
+            Resolution::new(1600., 900.).is_within_tolerance_of_common(16),
+            None
+        );
+    }
+
+    #[test]
+    fn bounding_common() {
+        assert_eq!(
+            Resolution::new(1000., 600.).bounding_common(),
+            Some(CommonResolutions::R720p)
+        );
+        assert_eq!(
+            Resolution::new(1300., 400.).bounding_common(),
+            Some(CommonResolutions::R1080p)
+        );
+        assert_eq!(
+            r1080p(AspectRatio::SIXTEEN_NINE).bounding_common(),
+            Some(CommonResolutions::R1080p)
+        );
+        assert_eq!(Resolution::new(3840., 2160.).bounding_common(), None);
+        assert_eq!(Resolution::new(1000., 1600.).bounding_common(), None);
+    }
+
+    #[test]
+    fn sharpness_score() {
+        let target = AspectRatio::SIXTEEN_NINE;
+        let r1440 = r1440p(AspectRatio::SIXTEEN_NINE);
+        let r1920_4x3 = Resolution::from_height(1920., AspectRatio::FOUR_THREE);
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(r1080.sharpness_score(target), r1080.area());
+        assert!(r1920_4x3.area() > r1440.area());
+        assert!(r1440.sharpness_score(target) > r1920_4x3.sharpness_score(target));
+        assert!(r1440.sharpness_score(target) > r1080.sharpness_score(target));
+        assert!(
+            r1920_4x3.sharpness_score(AspectRatio::FOUR_THREE)
+                > r1440.sharpness_score(AspectRatio::FOUR_THREE)
+        );
+    }
+
+    #[test]
+    fn equivalent() {
+        let dynamic = Resolution::new(1920., 1080.);
+        let set = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_ne!(dynamic, set);
+        assert!(dynamic.equivalent(&set));
+        assert!(set.equivalent(&dynamic));
+        assert!(!set.equivalent(&r720p(AspectRatio::SIXTEEN_NINE)));
+        assert!(!set.equivalent(&r1080p(AspectRatio::FOUR_THREE)));
+        assert!(!set.equivalent(&set.with_aspect_from(&r1080p(AspectRatio::FOUR_THREE))));
+    }
+
+    #[test]
+    fn round_to_supported() {
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+        let r900 = Resolution::from_height(900., AspectRatio::SIXTEEN_NINE);
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+        let supported = [r720, r900, r1080];
+
+        let just_past_midpoint = Resolution::from_height(815., AspectRatio::SIXTEEN_NINE);
+        assert_eq!(
+            just_past_midpoint.round_to_supported(&supported, None, 50.),
+            r900
+        );
+        assert_eq!(
+            just_past_midpoint.round_to_supported(&supported, Some(&r720), 50.),
+            r720
+        );
+
+        let near_900 = Resolution::from_height(880., AspectRatio::SIXTEEN_NINE);
+        assert_eq!(
+            near_900.round_to_supported(&supported, Some(&r720), 50.),
+            r900
+        );
+        assert_eq!(
+            near_900.round_to_supported(&supported, Some(&Resolution::new(1600., 880.)), 50.),
+            r900
+        );
+        assert_eq!(near_900.round_to_supported(&[], Some(&r720), 50.), near_900);
+    }
+
+    #[test]
+    fn larger_smaller() {
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert!(r1080.is_larger_than(&r720));
+        assert!(!r720.is_larger_than(&r1080));
+        assert!(r720.is_smaller_than(&r1080));
+        assert!(!r1080.is_smaller_than(&r720));
+
+        let same_area = Resolution::new(720., 1280.);
+        assert!(!r720.is_larger_than(&same_area));
+        assert!(!r720.is_smaller_than(&same_area));
+    }
+
+    #[test]
+    fn resolution_uvec2() {
+        let r360_1 = r360p(AspectRatio::SIXTEEN_NINE).into();
+        let r360_2 = r360p(AspectRatio::FOUR_THREE).into();
+        let r480_1 = r480p(AspectRatio::SIXTEEN_NINE).into();
+        let r480_2 = r480p(AspectRatio::FOUR_THREE).into();
+        let r720_1 = r720p(AspectRatio::SIXTEEN_NINE).into();
+        let r720_2 = r720p(AspectRatio::FOUR_THREE).into();
+        let r1080_1 = r1080p(AspectRatio::SIXTEEN_NINE).into();
+        let r1080_2 = r1080p(AspectRatio::FOUR_THREE).into();
+        let r1440_1 = r1440p(AspectRatio::SIXTEEN_NINE).into();
+        let r1440_2 = r1440p(AspectRatio::FOUR_THREE).into();
+        let r_custom = Resolution::from_height(240., AspectRatio::SIXTEEN_NINE).into();
+
+        assert_eq!(UVec2::new(427, 240), r_custom);
+
+        assert_eq!(UVec2::new(1920, 1440), r1440_2);
+        assert_eq!(UVec2::new(2560, 1440), r1440_1);
+
+        assert_eq!(UVec2::new(1920, 1080), r1080_1);
+        assert_eq!(UVec2::new(1440, 1080), r1080_2);
+
+        assert_eq!(UVec2::new(1280, 720), r720_1);
+        assert_eq!(UVec2::new(960, 720), r720_2);
+
+        assert_eq!(UVec2::new(854, 480), r480_1);
+        assert_eq!(UVec2::new(640, 480), r480_2);
+
+        assert_eq!(UVec2::new(640, 360), r360_1);
+        assert_eq!(UVec2::new(480, 360), r360_2);
+    }
+
+    #[test]
+    fn batch_conversion() {
+        let resolutions = [
+            r1080p(AspectRatio::SIXTEEN_NINE),
+            Resolution::from_height(240., AspectRatio::SIXTEEN_NINE),
+            Resolution::new(640., 480.),
+        ];
+
+        assert_eq!(
+            to_uvec2_vec(&resolutions),
+            vec![
+                UVec2::new(1920, 1080),
+                UVec2::new(427, 240),
+                UVec2::new(640, 480)
+            ]
+        );
+        assert_eq!(
+            to_vec2_vec(&resolutions),
+            vec![
+                Vec2::new(1920., 1080.),
+                Vec2::new(426.66666, 240.),
+                Vec2::new(640., 480.)
+            ]
+        );
+    }
+
+    #[test]
+    fn resolution_vec2() {
+        let r360_1 = r360p(AspectRatio::SIXTEEN_NINE).into();
+        let r360_2 = r360p(AspectRatio::FOUR_THREE).into();
+
+        let r480_1 = r480p(AspectRatio::SIXTEEN_NINE).into();
+        let r480_2 = r480p(AspectRatio::FOUR_THREE).into();
+
+        let r720_1 = r720p(AspectRatio::SIXTEEN_NINE).into();
+        let r720_2 = r720p(AspectRatio::FOUR_THREE).into();
+
+        let r1080_1 = r1080p(AspectRatio::SIXTEEN_NINE).into();
+        let r1080_2 = r1080p(AspectRatio::FOUR_THREE).into();
+
+        let r1440_1 = r1440p(AspectRatio::SIXTEEN_NINE).into();
+        let r1440_2 = r1440p(AspectRatio::FOUR_THREE).into();
+
+        let r_custom = Resolution::from_height(240., AspectRatio::SIXTEEN_NINE).into();
+
+        assert_eq!(Vec2::new(426.66666, 240.), r_custom);
+
+        assert_eq!(Vec2::new(1920., 1440.), r1440_2);
+        assert_eq!(Vec2::new(2560., 1440.), r1440_1);
+
+        assert_eq!(Vec2::new(1920., 1080.), r1080_1);
+        assert_eq!(Vec2::new(1440., 1080.), r1080_2);
+
+        assert_eq!(Vec2::new(1280., 720.), r720_1);
+        assert_eq!(Vec2::new(960., 720.), r720_2);
+
+        assert_eq!(Vec2::new(853.3333, 480.), r480_1);
+        assert_eq!(Vec2::new(640., 480.), r480_2);
+
+        assert_eq!(Vec2::new(640., 360.), r360_1);
+        assert_eq!(Vec2::new(480., 360.), r360_2);
+    }
+
+    #[test]
+    fn from_height_named() {
+        assert_eq!(
+            Resolution::from_height_named(1080., "16:9"),
+            Ok(r1080p(AspectRatio::SIXTEEN_NINE))
+        );
+        assert_eq!(
+            Resolution::from_height_named(480., " 4:3 "),
+            Ok(r480p(AspectRatio::FOUR_THREE))
+        );
+        assert_eq!(
+            Resolution::from_height_named(1080., "16:11"),
+            Err(ResolutionParseError::InvalidAspectRatio(
+                "16:11".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn at_height_for_ratios() {
+        let sixteen_ten = AspectRatio::try_from_pixels(16, 10).unwrap();
+        let resolutions = Resolution::at_height_for_ratios(
+            720.,
+            &[
+                AspectRatio::SIXTEEN_NINE,
+                AspectRatio::FOUR_THREE,
+                sixteen_ten,
+            ],
+        );
+
+        assert_eq!(
+            to_uvec2_vec(&resolutions),
+            vec![
+                UVec2::new(1280, 720),
+                UVec2::new(960, 720),
+                UVec2::new(1152, 720)
+            ]
+        );
+        assert_eq!(resolutions[2].aspect_ratio(), sixteen_ten);
+        assert!(Resolution::at_height_for_ratios(720., &[]).is_empty());
+    }
+
+    #[test]
+    fn from_width_exact() {
+        let r16x9 = Resolution::from_width_exact(1000., AspectRatio::SIXTEEN_NINE);
+        let r4x3 = Resolution::from_width_exact(1000., AspectRatio::FOUR_THREE);
+
+        assert_eq!(Vec2::from(r16x9), Vec2::new(1008., 567.));
+        assert_eq!(r16x9.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+        assert_eq!(Vec2::from(r4x3), Vec2::new(1000., 750.));
+        assert_eq!(r4x3.aspect_ratio(), AspectRatio::FOUR_THREE);
+        assert_eq!(
+            Resolution::from_width_exact(1920., AspectRatio::SIXTEEN_NINE),
+            r1080p(AspectRatio::SIXTEEN_NINE)
+        );
+        assert!(!Resolution::from_width_exact(1000., AspectRatio::SIXTEEN_NINE).is_fractional());
+
+        let sixteen_ten = AspectRatio::try_from_pixels(16, 10).unwrap();
+        let r16x10 = Resolution::from_width_exact(1000., sixteen_ten);
+        let r21x9 = Resolution::from_width_exact(1000., AspectRatio::ULTRAWIDE);
+
+        assert_eq!(Vec2::from(r16x10), Vec2::new(1000., 625.));
+        assert_eq!(r16x10.aspect_ratio(), sixteen_ten);
+        assert_eq!(Vec2::from(r21x9), Vec2::new(1001., 429.));
+        assert_eq!(r21x9.aspect_ratio(), AspectRatio::ULTRAWIDE);
+    }
+
+    #[test]
+    fn from_dimensions_detect() {
+        let r1080 = Resolution::from_dimensions_detect(1920., 1080.);
+        let r768 = Resolution::from_dimensions_detect(1366., 768.);
+        let r480 = Resolution::from_dimensions_detect(640., 480.);
+
+        assert_eq!(r1080, r1080p(AspectRatio::SIXTEEN_NINE));
+        assert_eq!(r768, Resolution::new(1366., 768.));
+        assert_eq!(r480, r480p(AspectRatio::FOUR_THREE));
+        assert_eq!(
+            r1080.change_height(720., true),
+            r720p(AspectRatio::SIXTEEN_NINE)
+        );
+    }
+
+    #[test]
+    fn from_common() {
+        assert_eq!(
+            Resolution::from_common(CommonResolutions::R720p),
+            Resolution::from(CommonResolutions::R720p)
+        );
+        assert_eq!(
+            Resolution::from_common(CommonResolutions::R720p).change_ratio(AspectRatio::FOUR_THREE),
+            Resolution::from_common_4x3(common4x3::CommonResolutions::R720p)
+        );
+    }
+
+    #[test]
+    fn from_integers() {
+        let tuple = Resolution::from((1920u32, 1080u32));
+        let array = Resolution::from([1280u32, 720u32]);
+
+        assert_eq!(tuple, Resolution::new(1920., 1080.));
+        assert_eq!(array.width(), 1280.);
+        assert_eq!(array.height(), 720.);
+    }
+
+    #[test]
+    fn aspect_ratio() {
+        let r360 = r360p(AspectRatio::SIXTEEN_NINE).into();
+
+        let r480 = r480p(AspectRatio::FOUR_THREE).into();
+
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE).into();
+
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE).into();
+
+        let r1440 = r1440p(AspectRatio::SIXTEEN_NINE).into();
+
+        let r_custom = Resolution::from_height(240., AspectRatio::SIXTEEN_NINE).aspect_ratio();
+
+        assert_eq!(AspectRatio::SIXTEEN_NINE, r_custom);
+
+        assert_eq!(AspectRatio::SIXTEEN_NINE, r1440);
+
+        assert_eq!(AspectRatio::SIXTEEN_NINE, r1080);
+
+        assert_eq!(AspectRatio::SIXTEEN_NINE, r720);
+
+        assert_eq!(AspectRatio::FOUR_THREE, r480);
+
+        assert_eq!(AspectRatio::SIXTEEN_NINE, r360);
+    }
+
+    #[test]
+    fn is_approx_ratio() {
+        let r768 = Resolution::new(1366., 768.);
+
+        assert!(r768.is_approx_ratio(&AspectRatio::SIXTEEN_NINE, 0.01));
+        assert!(!r768.is_approx_ratio(&AspectRatio::SIXTEEN_NINE, 0.0001));
+        assert!(!r768.is_approx_ratio(&AspectRatio::FOUR_THREE, 0.01));
+    }
+
+    #[test]
+    fn nearest_named_ratio() {
+        assert_eq!(
+            Resolution::new(1366., 768.).nearest_named_ratio(),
+            AspectRatio::SIXTEEN_NINE
+        );
+        assert_eq!(
+            Resolution::new(1280., 800.).nearest_named_ratio().ratio(),
+            1.6
+        );
+        assert_eq!(
+            Resolution::new(2560., 1080.).nearest_named_ratio(),
+            AspectRatio::ULTRAWIDE
+        );
+        assert_eq!(
+            Resolution::new(1280., 1024.).nearest_named_ratio(),
+            AspectRatio::FOUR_THREE
+        );
+    }
+
+    #[test]
+    fn aspect_ratio_name() {
+        assert_eq!(
+            r1080p(AspectRatio::SIXTEEN_NINE).aspect_ratio_name(),
+            Some("16:9")
+        );
+        assert_eq!(Resolution::new(640., 480.).aspect_ratio_name(), Some("4:3"));
+        assert_eq!(
+            Resolution::new(1080., 1080.).aspect_ratio_name(),
+            Some("1:1")
+        );
+        assert_eq!(Resolution::new(1366., 768.).aspect_ratio_name(), None);
+    }
+
+    #[test]
+    fn cached_resolution() {
+        let mut cached = CachedResolution::new(Resolution::new(1920., 1080.));
+
+        assert_eq!(cached.aspect_ratio().ratio(), 1920. / 1080.);
+
+        cached.set(cached.resolution().change_width(1440., false));
+        assert_eq!(cached.aspect_ratio().ratio(), 1440. / 1080.);
+        assert_eq!(cached.resolution(), Resolution::new(1440., 1080.));
+
+        cached.set(r720p(AspectRatio::ULTRAWIDE));
+        assert_eq!(cached.aspect_ratio(), AspectRatio::ULTRAWIDE);
+        assert_eq!(Resolution::from(cached), r720p(AspectRatio::ULTRAWIDE));
+    }
+
+    #[test]
+    fn distortion_percent_to() {
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(
+            r1080.distortion_percent_to(&r720p(AspectRatio::SIXTEEN_NINE)),
+            0.
+        );
+        assert!(
+            (r1080.distortion_percent_to(&r1080p(AspectRatio::FOUR_THREE)) - 25.).abs() < 0.001
+        );
+    }
+
+    #[test]
+    fn aspect_ratio_decimal() {
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+        let r480 = r480p(AspectRatio::FOUR_THREE);
+
+        assert_eq!(r1080.aspect_ratio_decimal(2), 1.78);
+        assert_eq!(r1080.aspect_ratio_decimal(3), 1.778);
+        assert_eq!(r480.aspect_ratio_decimal(2), 1.33);
+        assert_eq!(r480.aspect_ratio_decimal(3), 1.333);
+        assert_eq!(r1080.aspect_ratio_decimal(0), 2.);
+    }
+
+    #[test]
+    fn aspect_ratio_vec() {
+        assert_eq!(
+            Resolution::new(1920., 1080.).aspect_ratio_vec(),
+            Vec2::new(16., 9.)
+        );
+        assert_eq!(
+            Resolution::new(1280., 800.).aspect_ratio_vec(),
+            Vec2::new(16., 10.)
+        );
+        assert_eq!(
+            Resolution::new(1366., 768.).simplified_ratio(),
+            Some((683, 384))
+        );
+        assert_eq!(
+            r720p(AspectRatio::ULTRAWIDE).simplified_ratio(),
+            Some((21, 9))
+        );
+    }
+
+    #[test]
+    fn scale() {
+        let r360 = r360p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(
+            Vec2::from(r360.scale(Vec2::new(1., 2.))),
+            Vec2::new(640., 720.)
+        );
+        assert_eq!(
+            r360.scale(Vec2::splat(2.)),
+            r720p(AspectRatio::SIXTEEN_NINE)
+        );
+
+        assert_eq!(r360.scale_and_keep_aspect_ratio(Vec2::new(1., 2.)), None);
+        assert_eq!(
+            r360.scale_and_keep_aspect_ratio(Vec2::splat(2.)),
+            Some(r720p(AspectRatio::SIXTEEN_NINE))
+        );
+    }
+
+    #[test]
+    fn scale_each() {
+        let base = retro::r180p();
+
+        assert_eq!(base.scale_each(2, 2), r360p(AspectRatio::SIXTEEN_NINE));
+        assert_eq!(base.scale_each(2, 1), Resolution::new(640., 180.));
+        assert_eq!(base.scale_each(2, 1).aspect_ratio().ratio(), 640. / 180.);
+    }
+
+    #[test]
+    fn saturating_scale() {
+        let mut res = Resolution::new(2., 2.);
+
+        for _ in 0..4 {
+            res = res.half();
+        }
+        assert_eq!(res, Resolution::new(1., 1.));
+
+        assert_eq!(
+            Resolution::new(4., 2.).saturating_scale(Vec2::new(0.5, 0.25)),
+            Resolution::new(2., 1.)
+        );
+    }
+
+    #[test]
+    fn grow_to_ratio() {
+        let narrow = r1080p(AspectRatio::FOUR_THREE);
+        let wide = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(narrow.grow_to_ratio(AspectRatio::SIXTEEN_NINE), wide);
+        assert_eq!(
+            wide.grow_to_ratio(AspectRatio::FOUR_THREE),
+            r1440p(AspectRatio::FOUR_THREE)
+        );
+    }
+
+    #[test]
+    fn clamp_aspect_ratio() {
+        let super_ultrawide = Resolution::new(3840., 1080.);
+        let portrait = Resolution::new(1080., 1920.);
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(
+            super_ultrawide.clamp_aspect_ratio(AspectRatio::FOUR_THREE, AspectRatio::ULTRAWIDE),
+            r1080p(AspectRatio::ULTRAWIDE)
+        );
+        assert_eq!(
+            UVec2::from(
+                portrait.clamp_aspect_ratio(AspectRatio::FOUR_THREE, AspectRatio::ULTRAWIDE)
+            ),
+            UVec2::new(1080, 810)
+        );
+        assert_eq!(
+            r1080.clamp_aspect_ratio(AspectRatio::FOUR_THREE, AspectRatio::ULTRAWIDE),
+            r1080
+        );
+    }
+
+    #[test]
+    fn max_min() {
+        let tall = Resolution::new(720., 1280.);
+        let wide = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(tall.max(&wide), Resolution::new(1920., 1280.));
+        assert_eq!(tall.min(&wide), Resolution::new(720., 1080.));
+        assert_eq!(wide.max(&tall), tall.max(&wide));
+    }
+
+    #[test]
+    fn resize_keeping_area() {
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+        let resized = r1080.resize_keeping_area(AspectRatio::FOUR_THREE);
+
+        assert_eq!(resized.aspect_ratio(), AspectRatio::FOUR_THREE);
+        assert!((resized.width() * resized.height() - 1920. * 1080.).abs() < 1.);
+        assert_eq!(UVec2::from(resized.round()), UVec2::new(1663, 1247));
+    }
+
+    #[test]
+    fn try_scale_and_keep_aspect_ratio() {
+        let r360 = r360p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(
+            r360.try_scale_and_keep_aspect_ratio(Vec2::splat(2.)),
+            Ok(r720p(AspectRatio::SIXTEEN_NINE))
+        );
+        assert_eq!(
+            r360.try_scale_and_keep_aspect_ratio(Vec2::new(1., 2.)),
+            Err(ResolutionError::AspectRatioBroken {
+                expected: 16. / 9.,
+                got: 8. / 9.
+            })
+        );
+    }
+
+    #[test]
+    fn hor_plus() {
+        let design = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(
+            design.hor_plus(&Resolution::new(2560., 1080.)),
+            Resolution::new(2560., 1080.)
+        );
+        assert_eq!(
+            UVec2::from(design.hor_plus(&r720p(AspectRatio::FOUR_THREE))),
+            UVec2::new(1440, 1080)
+        );
+    }
+
+    #[test]
+    fn vert_minus() {
+        let design = r1080p(AspectRatio::SIXTEEN_NINE);
+        let ultrawide = design.vert_minus(&r1080p(AspectRatio::ULTRAWIDE));
+
+        assert_eq!(UVec2::from(ultrawide), UVec2::new(1920, 823));
+        assert!(ultrawide.height() < design.height());
+        assert_eq!(
+            design.vert_minus(&r720p(AspectRatio::FOUR_THREE)),
+            Resolution::new(1920., 1440.)
+        );
+    }
+
+    #[test]
+    fn scale_down() {
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(r1080.scale_down(3), Resolution::new(640., 360.));
+        assert_eq!(r1080.scale_down(7), Resolution::new(274., 154.));
+        assert_eq!(r1080.scale_down(2000), Resolution::new(1., 1.));
+
+        let r240 = Resolution::from_height(240., AspectRatio::SIXTEEN_NINE);
+        assert_eq!(r240.scale_down(1), Resolution::new(427., 240.));
+        assert_eq!(r240.scale_down(2), Resolution::new(213., 120.));
+    }
+
+    #[test]
+    fn div() {
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+        let mut r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(r720 / 2., r360p(AspectRatio::SIXTEEN_NINE));
+        assert_eq!(r720 / Vec2::new(2., 2.), r360p(AspectRatio::SIXTEEN_NINE));
+
+        let stretched = r720 / Vec2::new(1., 2.);
+        assert_eq!(Vec2::from(stretched), Vec2::new(1280., 360.));
+        assert_ne!(stretched.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+
+        r1080 /= 3.;
+        assert_eq!(UVec2::from(r1080), UVec2::new(640, 360));
+        assert_eq!(r1080.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot divide a resolution by zero")]
+    fn div_by_zero() {
+        let _ = r720p(AspectRatio::SIXTEEN_NINE) / Vec2::new(2., 0.);
+    }
+
+    #[test]
+    fn scale_reporting() {
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+        let dynamic = Resolution::new(1280., 720.);
+
+        let (uniform, dropped) = r720.scale_reporting(Vec2::splat(1.5));
+        assert_eq!(uniform, r1080p(AspectRatio::SIXTEEN_NINE));
+        assert!(!dropped);
+
+        let (stretched, dropped) = r720.scale_reporting(Vec2::new(1., 2.));
+        assert_eq!(stretched, Resolution::new(1280., 1440.));
+        assert!(dropped);
+
+        assert!(!dynamic.scale_reporting(Vec2::new(1., 2.)).1);
+    }
+
+    #[test]
+    fn scale_round_trip() {
+        let bases = [
+            r720p(AspectRatio::SIXTEEN_NINE),
+            r1080p(AspectRatio::FOUR_THREE),
+            Resolution::from_height(1000., AspectRatio::ULTRAWIDE),
+            Resolution::new(1366., 768.),
+        ];
+
+        for base in bases {
+            for k in [0.1, 0.3, 0.5, 0.7, 1.5, 3., 7.3, 11.] {
+                let round_trip = base.scale(Vec2::splat(k)).scale(Vec2::splat(1. / k));
+
+                assert!(
+                    (round_trip.width() - base.width()).abs() <= EPSILON * base.width()
+                        && (round_trip.height() - base.height()).abs() <= EPSILON * base.height(),
+                    "{base:?} scaled by {k} came back as {round_trip:?}"
+                );
+                assert_eq!(
+                    round_trip.aspect_ratio, base.aspect_ratio,
+                    "{base:?} scaled by {k} came back as {round_trip:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn nearest_pow2() {
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+        let pow2 = Resolution::new(512., 256.);
+
+        assert_eq!(
+            r1080.nearest_pow2(RoundMode::Up),
+            Resolution::new(2048., 2048.)
+        );
+        assert_eq!(
+            r1080.nearest_pow2(RoundMode::Down),
+            Resolution::new(1024., 1024.)
+        );
+        assert_eq!(
+            r1080.nearest_pow2(RoundMode::Nearest),
+            Resolution::new(2048., 1024.)
+        );
+        assert_eq!(pow2.nearest_pow2(RoundMode::Up), pow2);
+        assert_eq!(pow2.nearest_pow2(RoundMode::Down), pow2);
+        assert_eq!(pow2.nearest_pow2(RoundMode::Nearest), pow2);
+        assert_eq!(
+            Resolution::new(0.5, 3.).nearest_pow2(RoundMode::Down),
+            Resolution::new(1., 2.)
+        );
+
+        let huge = Resolution::new(3e9, 1e30);
+        assert_eq!(
+            huge.nearest_pow2(RoundMode::Up),
+            Resolution::new(4294967296., 9223372036854775808.)
+        );
+        assert_eq!(
+            huge.nearest_pow2(RoundMode::Nearest),
+            Resolution::new(2147483648., 9223372036854775808.)
+        );
+    }
+
+    #[test]
+    fn texel_size() {
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+        let texel = r1080.texel_size();
+
+        assert!((texel.x - 0.00052).abs() < 0.000001);
+        assert!((texel.y - 0.000926).abs() < 0.000001);
+        assert_eq!(r1080.inverse(), texel);
+        assert_eq!(Resolution::new(4., 2.).texel_size(), Vec2::new(0.25, 0.5));
+    }
+
+    #[test]
+    fn half_and_double() {
+        assert_eq!(
+            UVec2::from(r720p(AspectRatio::SIXTEEN_NINE).half()),
+            UVec2::new(640, 360)
+        );
+        assert_eq!(
+            UVec2::from(r480p(AspectRatio::SIXTEEN_NINE).half()),
+            UVec2::new(426, 240)
+        );
+        assert_eq!(
+            r360p(AspectRatio::SIXTEEN_NINE).double(),
+            r720p(AspectRatio::SIXTEEN_NINE)
+        );
+    }
+
+    #[test]
+    fn logical_physical() {
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(UVec2::from(r720.as_physical(2.)), UVec2::new(2560, 1440));
+        assert_eq!(r720.as_physical(1.5).as_logical(1.5), r720);
+        assert_eq!(
+            r720.as_physical(1.5).aspect_ratio(),
+            AspectRatio::SIXTEEN_NINE
+        );
+    }
+
+    #[test]
+    fn tile_count() {
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(r1080.tile_count(&Resolution::new(256., 256.)), (7, 4));
+        assert_eq!(r1080.total_tiles(&Resolution::new(256., 256.)), 28);
+        assert_eq!(r720.tile_count(&Resolution::new(64., 48.)), (20, 15));
+        assert_eq!(r720.total_tiles(&Resolution::new(64., 48.)), 300);
+        assert_eq!(r720.tile_count(&r1080), (0, 0));
+        assert_eq!(r720.tile_count(&Resolution::new(0., 0.)), (0, 0));
+        assert_eq!(r720.total_tiles(&Resolution::new(64., 0.)), 0);
+        assert_eq!(r720.tile_count(&Resolution::new(-64., 48.)), (0, 0));
+        assert_eq!(r720.tile_count(&Resolution::new(f32::NAN, 48.)), (0, 0));
+    }
+
+    #[test]
+    fn steps_to() {
+        let base = retro::r180p();
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+        let steps = base.steps_to(&r1080);
+
+        assert_eq!(steps.len(), 6);
+        assert_eq!(steps[0], base);
+        assert_eq!(*steps.last().unwrap(), r1080);
+        assert!(base.steps_to(&Resolution::new(100., 100.)).is_empty());
+        assert!(Resolution::new(0., 0.).steps_to(&r1080).is_empty());
+        assert!(Resolution::new(-320., 180.).steps_to(&r1080).is_empty());
+        assert!(Resolution::new(f32::NAN, 180.).steps_to(&r1080).is_empty());
+    }
+
+    #[test]
+    fn integer_scales_within() {
+        let base = retro::r180p();
+        let scales = base.integer_scales_within(&r1080p(AspectRatio::SIXTEEN_NINE));
+
+        assert_eq!(
+            scales
+                .iter()
+                .map(|(factor, _)| *factor)
+                .collect::<Vec<u32>>(),
+            vec![1, 2, 3, 4, 5, 6]
+        );
+        assert_eq!(
+            scales[2],
+            (3, Resolution::from_height(540., AspectRatio::SIXTEEN_NINE))
+        );
+        assert_eq!(UVec2::from(scales[2].1), UVec2::new(960, 540));
+    }
+
+    #[test]
+    fn iter_common_scales() {
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+        let scales = r720
+            .iter_common_scales()
+            .collect::<Vec<(f32, Resolution)>>();
+
+        assert_eq!(scales.len(), 4);
+        assert_eq!(scales[0], (1., r720));
+        assert_eq!(UVec2::from(scales[1].1), UVec2::new(1600, 900));
+        assert_eq!(scales[3], (2., r720.double()));
+        assert_eq!(scales[3].1.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+    }
+
+    #[test]
+    fn rounding() {
+        let r240 = Resolution::from_height(240., AspectRatio::SIXTEEN_NINE);
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(r240.floor(), Resolution::new(426., 240.));
+        assert_eq!(r240.ceil(), Resolution::new(427., 240.));
+        assert_eq!(r240.round(), Resolution::new(427., 240.));
+        assert_eq!(r1080.floor(), r1080);
+        assert_eq!(r1080.round().aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+    }
+
+    #[test]
+    fn with_min_size() {
+        let min = Resolution::new(800., 600.);
+        let r360 = r360p(AspectRatio::SIXTEEN_NINE);
+        let wide = Resolution::new(1600., 400.);
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        let grown = r360.with_min_size(&min);
+        assert_eq!(grown.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+        assert!(approx_eq(grown.height(), 600.));
+        assert!(grown.width() > 1066. && grown.width() < 1067.);
+
+        // Only the height is below the minimum, yet both axes grow.
+        assert_eq!(wide.with_min_size(&min), Resolution::new(2400., 600.));
+        assert_eq!(r1080.with_min_size(&min), r1080);
+        assert_eq!(Resolution::new(0., 0.).with_min_size(&min), min);
+        assert_eq!(Resolution::new(0., 360.).with_min_size(&min), min);
+    }
+
+    #[test]
+    fn responsive_fit() {
+        let design = r1080p(AspectRatio::SIXTEEN_NINE);
+        let min = r360p(AspectRatio::SIXTEEN_NINE);
+        let max = r1440p(AspectRatio::SIXTEEN_NINE);
+
+        let fitted = design.responsive_fit(&Resolution::new(1024., 768.), &min, &max);
+        assert!(approx_eq(fitted.width(), 1024.));
+        assert!(approx_eq(fitted.height(), 576.));
+        assert_eq!(fitted.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+
+        assert!(design
+            .responsive_fit(&Resolution::new(300., 200.), &min, &max)
+            .equivalent(&min));
+        assert!(design
+            .responsive_fit(&Resolution::new(7680., 4320.), &min, &max)
+            .equivalent(&max));
+    }
+
+    #[test]
+    fn scale_clamped() {
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+        let min = r360p(AspectRatio::SIXTEEN_NINE);
+        let max = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(r720.scale_clamped(2., &min, &max), max);
+        assert_eq!(r720.scale_clamped(0.25, &min, &max), min);
+        assert_eq!(
+            UVec2::from(r720.scale_clamped(1.25, &min, &max)),
+            UVec2::new(1600, 900)
+        );
+        assert_eq!(
+            r720.scale_clamped(4., &min, &Resolution::new(1000., 1000.))
+                .aspect_ratio(),
+            AspectRatio::SIXTEEN_NINE
+        );
+    }
+
+    #[test]
+    fn clamp_megapixels() {
+        let r4k = Resolution::from_height(2160., AspectRatio::SIXTEEN_NINE);
+        let clamped = r4k.clamp_megapixels(2.);
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+
+        assert!((clamped.megapixels() - 2.).abs() < 0.001);
+        assert_eq!(clamped.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+        assert_eq!(UVec2::from(clamped.round()), UVec2::new(1886, 1061));
+        assert_eq!(r720.clamp_megapixels(2.), r720);
+    }
+
+    #[test]
+    fn pixel_budget() {
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+        let from_budget = Resolution::from_pixel_budget(1920 * 1080, AspectRatio::SIXTEEN_NINE);
+
+        assert!(approx_eq(from_budget.width(), 1920.));
+        assert!(approx_eq(from_budget.height(), 1080.));
+        assert_eq!(from_budget.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+
+        let clamped = r1080.clamp_to_pixel_budget(1280 * 720);
+        assert!(approx_eq(clamped.width(), 1280.));
+        assert!(approx_eq(clamped.height(), 720.));
+        assert_eq!(clamped.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+        assert_eq!(r1080.clamp_to_pixel_budget(2560 * 1440), r1080);
+    }
+
+    #[test]
+    fn ensure_even() {
+        let odd_width = Resolution::from_height(240., AspectRatio::SIXTEEN_NINE).ensure_even();
+        let odd_height = Resolution::new(640., 361.).ensure_even();
+        let even = r1080p(AspectRatio::SIXTEEN_NINE).ensure_even();
+
+        assert_eq!(UVec2::from(odd_width), UVec2::new(428, 240));
+        assert_eq!(UVec2::from(odd_height), UVec2::new(640, 362));
+        assert_eq!(even, r1080p(AspectRatio::SIXTEEN_NINE));
+    }
+
+    #[test]
+    fn center_offset_in() {
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(r720.center_offset_in(&r1080), Vec2::new(320., 180.));
+        assert_eq!(r1080.center_offset_in(&r720), Vec2::new(-320., -180.));
+        assert_eq!(
+            r480p(AspectRatio::FOUR_THREE).center_offset_in(&r720),
+            Vec2::new(320., 120.)
+        );
+    }
+
+    #[test]
+    fn grid_viewports() {
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert_eq!(
+            r1080.grid_viewports(2, 1),
+            vec![
+                (UVec2::new(0, 0), UVec2::new(960, 1080)),
+                (UVec2::new(960, 0), UVec2::new(960, 1080)),
+            ]
+        );
+        assert_eq!(
+            r1080.grid_viewports(2, 2),
+            vec![
+                (UVec2::new(0, 0), UVec2::new(960, 540)),
+                (UVec2::new(960, 0), UVec2::new(960, 540)),
+                (UVec2::new(0, 540), UVec2::new(960, 540)),
+                (UVec2::new(960, 540), UVec2::new(960, 540)),
+            ]
+        );
+        assert_eq!(
+            Resolution::new(1001., 601.).grid_viewports(3, 2),
+            vec![
+                (UVec2::new(0, 0), UVec2::new(333, 300)),
+                (UVec2::new(333, 0), UVec2::new(333, 300)),
+                (UVec2::new(666, 0), UVec2::new(335, 300)),
+                (UVec2::new(0, 300), UVec2::new(333, 301)),
+                (UVec2::new(333, 300), UVec2::new(333, 301)),
+                (UVec2::new(666, 300), UVec2::new(335, 301)),
+            ]
+        );
+        assert!(r1080.grid_viewports(0, 2).is_empty());
+        assert!(Resolution::new(1., 1.).grid_viewports(3, 1).is_empty());
+        assert_eq!(
+            Resolution::new(3., 1.).grid_viewports(3, 1),
+            vec![
+                (UVec2::new(0, 0), UVec2::new(1, 1)),
+                (UVec2::new(1, 0), UVec2::new(1, 1)),
+                (UVec2::new(2, 0), UVec2::new(1, 1)),
+            ]
+        );
     }
 
     #[test]
-    fn can_integer_scale() {
-        assert!(!has_integer_scale(
-            &r360p(AspectRatio::FOUR_THREE),
-            &r480p(AspectRatio::FOUR_THREE)
-        ));
-        assert!(has_integer_scale(
-            &r360p(AspectRatio::SIXTEEN_NINE),
-            &r720p(AspectRatio::SIXTEEN_NINE)
-        ));
-        assert!(!has_integer_scale(
-            &r360p(AspectRatio::SIXTEEN_NINE),
-            &r720p(AspectRatio::FOUR_THREE)
-        ));
+    fn cover_rect() {
+        let wide = r1080p(AspectRatio::SIXTEEN_NINE);
+        let narrow = r1080p(AspectRatio::FOUR_THREE);
+        let square = Resolution::new(1080., 1080.);
+
+        assert_eq!(
+            wide.cover_rect(&square),
+            (Vec2::new(420., 0.), Vec2::new(1080., 1080.))
+        );
+        assert_eq!(
+            narrow.cover_rect(&wide),
+            (Vec2::new(0., 135.), Vec2::new(1440., 810.))
+        );
     }
 
     #[test]
-    fn get_scale() {
+    fn viewport_rect() {
+        let wide = r1080p(AspectRatio::SIXTEEN_NINE);
+        let narrow = r1080p(AspectRatio::FOUR_THREE);
+
         assert_eq!(
-            get_scale_factor(
-                &r360p(AspectRatio::SIXTEEN_NINE),
-                &r720p(AspectRatio::SIXTEEN_NINE)
-            ),
-            Vec2::splat(2.)
+            wide.viewport_rect(&narrow),
+            (Vec2::new(0., 135.), Vec2::new(1440., 810.))
         );
         assert_eq!(
-            get_scale_factor(
-                &r720p(AspectRatio::SIXTEEN_NINE),
-                &r360p(AspectRatio::SIXTEEN_NINE)
-            ),
-            Vec2::splat(0.5)
+            narrow.viewport_rect(&wide),
+            (Vec2::new(240., 0.), Vec2::new(1440., 1080.))
         );
     }
 
     #[test]
-    fn resolution_uvec2() {
-        let r360_1 = r360p(AspectRatio::SIXTEEN_NINE).into();
-        let r360_2 = r360p(AspectRatio::FOUR_THREE).into();
-        let r480_1 = r480p(AspectRatio::SIXTEEN_NINE).into();
-        let r480_2 = r480p(AspectRatio::FOUR_THREE).into();
-        let r720_1 = r720p(AspectRatio::SIXTEEN_NINE).into();
-        let r720_2 = r720p(AspectRatio::FOUR_THREE).into();
-        let r1080_1 = r1080p(AspectRatio::SIXTEEN_NINE).into();
-        let r1080_2 = r1080p(AspectRatio::FOUR_THREE).into();
-        let r1440_1 = r1440p(AspectRatio::SIXTEEN_NINE).into();
-        let r1440_2 = r1440p(AspectRatio::FOUR_THREE).into();
-        let r_custom = Resolution::from_height(240., AspectRatio::SIXTEEN_NINE).into();
+    fn repeated_changes_keep_ratio() {
+        let mut res = r1080p(AspectRatio::SIXTEEN_NINE);
 
-        assert_eq!(UVec2::new(427, 240), r_custom);
+        for i in 0..100 {
+            let height = if i % 2 == 0 { 733. } else { 1080. };
+            res = res.change_height(height, true);
 
-        assert_eq!(UVec2::new(1920, 1440), r1440_2);
-        assert_eq!(UVec2::new(2560, 1440), r1440_1);
+            assert_eq!(res.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+            assert_eq!(res.width(), height * AspectRatio::SIXTEEN_NINE.ratio());
+        }
+        assert_eq!(res, r1080p(AspectRatio::SIXTEEN_NINE));
+    }
 
-        assert_eq!(UVec2::new(1920, 1080), r1080_1);
-        assert_eq!(UVec2::new(1440, 1080), r1080_2);
+    #[test]
+    fn scale_to_fit_detailed() {
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+        let (factor, fitted) = r720.scale_to_fit_detailed(&r1080p(AspectRatio::FOUR_THREE));
 
-        assert_eq!(UVec2::new(1280, 720), r720_1);
-        assert_eq!(UVec2::new(960, 720), r720_2);
+        assert_eq!(factor, 1.125);
+        assert_eq!(Vec2::from(r720) * factor, Vec2::from(fitted));
+        assert_eq!(fitted.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
 
-        assert_eq!(UVec2::new(854, 480), r480_1);
-        assert_eq!(UVec2::new(640, 480), r480_2);
+        let (factor, fitted) = retro::r180p().scale_to_fit_detailed(&r720);
+        assert_eq!(factor, 4.);
+        assert_eq!(fitted, r720);
 
-        assert_eq!(UVec2::new(640, 360), r360_1);
-        assert_eq!(UVec2::new(480, 360), r360_2);
+        let empty = Resolution::new(0., 0.);
+        assert_eq!(empty.scale_to_fit_detailed(&r720), (1., empty));
     }
 
     #[test]
-    fn resolution_vec2() {
-        let r360_1 = r360p(AspectRatio::SIXTEEN_NINE).into();
-        let r360_2 = r360p(AspectRatio::FOUR_THREE).into();
+    fn resize_locked() {
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+
+        let wider = r720.resize_locked(Vec2::new(1920., 740.));
+        assert_eq!(wider.width(), 1920.);
+        assert!(approx_eq(wider.height(), 1080.));
+        assert_eq!(wider.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+
+        let taller = r720.resize_locked(Vec2::new(1300., 360.));
+        assert!(approx_eq(taller.width(), 640.));
+        assert_eq!(taller.height(), 360.);
+        assert_eq!(taller.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+    }
 
-        let r480_1 = r480p(AspectRatio::SIXTEEN_NINE).into();
-        let r480_2 = r480p(AspectRatio::FOUR_THREE).into();
+    #[test]
+    fn lerp() {
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+        let r480 = r480p(AspectRatio::FOUR_THREE);
 
-        let r720_1 = r720p(AspectRatio::SIXTEEN_NINE).into();
-        let r720_2 = r720p(AspectRatio::FOUR_THREE).into();
+        assert_eq!(r720.lerp(&r1080, 0.), r720);
+        assert_eq!(r720.lerp(&r1080, 1.), r1080);
+        assert_eq!(
+            r720.lerp(&r1080, 0.5),
+            Resolution::from_height(900., AspectRatio::SIXTEEN_NINE)
+        );
+        assert_eq!(r720.lerp(&r1080, 3.), r1080);
+        assert_eq!(r720.lerp(&r480, 0.5), Resolution::new(960., 600.));
+    }
 
-        let r1080_1 = r1080p(AspectRatio::SIXTEEN_NINE).into();
-        let r1080_2 = r1080p(AspectRatio::FOUR_THREE).into();
+    #[test]
+    fn interpolate_safe() {
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+        let r1080 = r1080p(AspectRatio::FOUR_THREE);
 
-        let r1440_1 = r1440p(AspectRatio::SIXTEEN_NINE).into();
-        let r1440_2 = r1440p(AspectRatio::FOUR_THREE).into();
+        assert_eq!(
+            r720.interpolate_safe(&r1080, 0.),
+            Resolution::new(1280., 720.)
+        );
+        assert_eq!(
+            r720.interpolate_safe(&r1080, 0.5),
+            Resolution::new(1360., 900.)
+        );
+        assert_eq!(
+            r720.interpolate_safe(&r1080, 2.),
+            Resolution::new(1440., 1080.)
+        );
 
-        let r_custom = Resolution::from_height(240., AspectRatio::SIXTEEN_NINE).into();
+        let sliver = Resolution::new(0.001, 1000.);
+        let collapsed = Resolution::new(0., 0.);
+        for t in [0., 0.001, 0.25, 0.5, 0.999, 1., f32::NAN] {
+            for (from, to) in [(&sliver, &r720), (&r720, &collapsed), (&collapsed, &sliver)] {
+                let frame = from.interpolate_safe(to, t);
 
-        assert_eq!(Vec2::new(426.66666, 240.), r_custom);
+                assert!(frame.width() >= 1. && frame.height() >= 1.);
+                assert!(frame.aspect_ratio().ratio().is_finite());
+            }
+        }
+    }
 
-        assert_eq!(Vec2::new(1920., 1440.), r1440_2);
-        assert_eq!(Vec2::new(2560., 1440.), r1440_1);
+    #[test]
+    fn with_aspect_from() {
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+        let dynamic = Resolution::new(1280., 720.);
+        let r480 = r480p(AspectRatio::FOUR_THREE);
+
+        assert_eq!(dynamic.with_aspect_from(&r720), r720);
+        assert_eq!(r720.with_aspect_from(&dynamic), dynamic);
+
+        let mismatched = dynamic.with_aspect_from(&r480);
+        assert_eq!(Vec2::from(mismatched), Vec2::new(1280., 720.));
+        assert_eq!(mismatched.aspect_ratio(), AspectRatio::FOUR_THREE);
+        assert!(!mismatched.is_consistent());
+    }
 
-        assert_eq!(Vec2::new(1920., 1080.), r1080_1);
-        assert_eq!(Vec2::new(1440., 1080.), r1080_2);
+    #[test]
+    fn changes() {
+        let r360 = r360p(AspectRatio::SIXTEEN_NINE);
 
-        assert_eq!(Vec2::new(1280., 720.), r720_1);
-        assert_eq!(Vec2::new(960., 720.), r720_2);
+        assert_eq!(r360.change_height(720., false).width(), 640.);
+        assert_eq!(r360.change_height(720., true).width(), 1280.);
+        assert_eq!(r360.change_width(1280., true).height(), 720.);
+        assert_eq!(r360.change_width(1280., false).height(), 360.);
+        assert_eq!(
+            UVec2::from(r360.change_ratio(AspectRatio::FOUR_THREE)),
+            UVec2::new(480, 360)
+        );
+        assert_eq!(
+            UVec2::from(r360.change_ratio(AspectRatio::ULTRAWIDE)),
+            UVec2::new(840, 360)
+        );
+    }
 
-        assert_eq!(Vec2::new(853.3333, 480.), r480_1);
-        assert_eq!(Vec2::new(640., 480.), r480_2);
+    #[test]
+    fn blend_aspect() {
+        let r1080 = r1080p(AspectRatio::FOUR_THREE);
 
-        assert_eq!(Vec2::new(640., 360.), r360_1);
-        assert_eq!(Vec2::new(480., 360.), r360_2);
+        assert_eq!(r1080.blend_aspect(AspectRatio::SIXTEEN_NINE, 0.), r1080);
+        assert_eq!(
+            r1080.blend_aspect(AspectRatio::SIXTEEN_NINE, 1.),
+            r1080p(AspectRatio::SIXTEEN_NINE)
+        );
+        assert_eq!(
+            r1080.blend_aspect(AspectRatio::SIXTEEN_NINE, 2.),
+            r1080p(AspectRatio::SIXTEEN_NINE)
+        );
+        assert_eq!(
+            UVec2::from(r1080.blend_aspect(AspectRatio::SIXTEEN_NINE, 0.5)),
+            UVec2::new(1680, 1080)
+        );
     }
 
     #[test]
-    fn aspect_ratio() {
-        let r360 = r360p(AspectRatio::SIXTEEN_NINE).into();
+    fn is_consistent() {
+        let inconsistent = Resolution {
+            width: 1000.,
+            height: 1080.,
+            aspect_ratio: AspectRatioMode::Set(AspectRatio::SIXTEEN_NINE),
+        };
+
+        assert!(r1080p(AspectRatio::SIXTEEN_NINE).is_consistent());
+        assert!(Resolution::from_height(240., AspectRatio::SIXTEEN_NINE).is_consistent());
+        assert!(Resolution::new(1000., 1080.).is_consistent());
+        assert!(!inconsistent.is_consistent());
+        assert!(inconsistent.change_height(720., true).is_consistent());
+
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+        assert_eq!(
+            r1080.change_width(f32::INFINITY, true).height(),
+            f32::INFINITY
+        );
+        assert!(r1080.change_height(f32::NAN, true).width().is_nan());
+    }
 
-        let r480 = r480p(AspectRatio::FOUR_THREE).into();
+    #[test]
+    fn try_change_ratio() {
+        let r360 = r360p(AspectRatio::SIXTEEN_NINE);
 
-        let r720 = r720p(AspectRatio::SIXTEEN_NINE).into();
+        assert_eq!(
+            r360.try_change_ratio(4., 3.),
+            Ok(r360.change_ratio(AspectRatio::FOUR_THREE))
+        );
+        assert_eq!(
+            r360.try_change_ratio(4., 0.),
+            Err(ResolutionError::InvalidAspectRatio {
+                width: 4.,
+                height: 0.
+            })
+        );
+        assert_eq!(
+            r360.try_change_ratio(-4., 3.),
+            Err(ResolutionError::InvalidAspectRatio {
+                width: -4.,
+                height: 3.
+            })
+        );
+        assert!(r360.try_change_ratio(f32::NAN, 3.).is_err());
+    }
 
-        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE).into();
+    #[test]
+    fn config_string() {
+        let set = r1080p(AspectRatio::SIXTEEN_NINE);
+        let fractional = Resolution::from_height(240., AspectRatio::SIXTEEN_NINE);
+        let ultrawide = r1080p(AspectRatio::ULTRAWIDE);
+        let dynamic = Resolution::new(1366., 768.);
+
+        assert_eq!(set.to_config_string(), "1920x1080@16:9");
+        assert_eq!(dynamic.to_config_string(), "1366x768");
+
+        for res in [set, fractional, ultrawide, dynamic] {
+            assert_eq!(
+                Resolution::from_config_string(&res.to_config_string()),
+                Ok(res)
+            );
+        }
+    }
 
-        let r1440 = r1440p(AspectRatio::SIXTEEN_NINE).into();
+    #[test]
+    fn config_string_errors() {
+        assert_eq!(
+            Resolution::from_config_string("1920"),
+            Err(ResolutionParseError::InvalidFormat)
+        );
+        assert_eq!(
+            Resolution::from_config_string("1920xabc"),
+            Err(ResolutionParseError::InvalidDimension("abc".to_string()))
+        );
+        assert_eq!(
+            Resolution::from_config_string("1920x1080@16:0"),
+            Err(ResolutionParseError::InvalidAspectRatio("16:0".to_string()))
+        );
+    }
 
-        let r_custom = Resolution::from_height(240., AspectRatio::SIXTEEN_NINE).aspect_ratio();
+    #[test]
+    fn parse_fractional() {
+        let parsed: Resolution = "426.67x240".parse().unwrap();
 
-        assert_eq!(AspectRatio::SIXTEEN_NINE, r_custom);
+        assert_eq!(parsed, Resolution::new(426.67, 240.));
+        assert!((parsed.width() - 426.67).abs() < EPSILON);
+        assert_eq!(
+            "426.67x240.5@16:9".parse(),
+            Ok(Resolution::new(426.67, 240.5).with_aspect_from(&r360p(AspectRatio::SIXTEEN_NINE)))
+        );
+        assert_eq!(
+            super::parse_list("426.67x240,1280x720"),
+            Ok(vec![
+                Resolution::new(426.67, 240.),
+                Resolution::new(1280., 720.)
+            ])
+        );
+    }
 
-        assert_eq!(AspectRatio::SIXTEEN_NINE, r1440);
+    #[test]
+    fn parse_list() {
+        assert_eq!(
+            super::parse_list("1920x1080, 1280x720,640x360@16:9"),
+            Ok(vec![
+                Resolution::new(1920., 1080.),
+                Resolution::new(1280., 720.),
+                r360p(AspectRatio::SIXTEEN_NINE),
+            ])
+        );
+        assert_eq!(super::parse_list(""), Ok(vec![]));
+        assert_eq!(
+            super::parse_list("1920x1080,1280by720,640x360"),
+            Err(ResolutionParseError::InvalidListEntry {
+                index: 1,
+                error: Box::new(ResolutionParseError::InvalidFormat),
+            })
+        );
+        assert_eq!("1280x720".parse(), Ok(Resolution::new(1280., 720.)));
+    }
 
-        assert_eq!(AspectRatio::SIXTEEN_NINE, r1080);
+    #[test]
+    fn bytes() {
+        let dynamic = Resolution::new(1366., 768.);
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+        let r1000 = Resolution::from_height(1000., AspectRatio::try_new(1.5, 1.).unwrap());
+
+        assert_eq!(Resolution::from_bytes(&dynamic.to_bytes()), Ok(dynamic));
+        assert_eq!(Resolution::from_bytes(&r1080.to_bytes()), Ok(r1080));
+        assert_eq!(Resolution::from_bytes(&r1000.to_bytes()), Ok(r1000));
+        assert_eq!(r1080.to_bytes(), [0x80, 0x07, 0, 0, 0x38, 0x04, 0, 0, 4]);
+
+        let r240 = Resolution::from_height(240., AspectRatio::SIXTEEN_NINE);
+        let decoded = Resolution::from_bytes(&r240.to_bytes()).unwrap();
+        assert_eq!(r240.to_bytes()[8], DIMENSIONS_TAG);
+        assert_eq!(UVec2::from(decoded), UVec2::new(427, 240));
+        assert_eq!(
+            decoded.aspect_ratio(),
+            AspectRatio::try_from_pixels(427, 240).unwrap()
+        );
+        assert!(decoded.is_consistent());
 
-        assert_eq!(AspectRatio::SIXTEEN_NINE, r720);
+        assert_eq!(
+            Resolution::from_bytes(&r1080.to_bytes()[..8]),
+            Err(ResolutionError::InvalidEncoding)
+        );
+        assert_eq!(
+            Resolution::from_bytes(&[0x80, 0x07, 0, 0, 0x38, 0x04, 0, 0, 4, 0, 0]),
+            Err(ResolutionError::InvalidEncoding)
+        );
+        assert_eq!(
+            Resolution::from_bytes(&[0x80, 0x07, 0, 0, 0x38, 0x04, 0, 0, 42]),
+            Err(ResolutionError::InvalidEncoding)
+        );
+        assert_eq!(
+            Resolution::from_bytes(&[0x80, 0x07, 0, 0, 0, 0, 0, 0, 0]),
+            Err(ResolutionError::InvalidAspectRatio {
+                width: 1920.,
+                height: 0.
+            })
+        );
+    }
 
-        assert_eq!(AspectRatio::FOUR_THREE, r480);
+    #[test]
+    fn string_compact() {
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
 
-        assert_eq!(AspectRatio::SIXTEEN_NINE, r360);
+        assert_eq!(r1080.to_string_compact(), "1920x1080");
+        assert_eq!(r1080.to_string(), "1920 x 1080");
     }
 
     #[test]
-    fn scale() {
-        let r360 = r360p(AspectRatio::SIXTEEN_NINE);
+    fn key_string() {
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+        let dynamic = Resolution::new(1920., 1080.);
 
+        assert_eq!(r1080.to_key_string(), "1920x1080:16:9");
+        assert_eq!(dynamic.to_key_string(), "1920x1080:dyn");
         assert_eq!(
-            Vec2::from(r360.scale(Vec2::new(1., 2.))),
-            Vec2::new(640., 720.)
+            r1080.to_key_string(),
+            Resolution::from_common(CommonResolutions::R1080p).to_key_string()
         );
-        assert_eq!(
-            r360.scale(Vec2::splat(2.)),
-            r720p(AspectRatio::SIXTEEN_NINE)
+        assert_ne!(r1080.to_key_string(), dynamic.to_key_string());
+        assert_ne!(
+            r1080.to_key_string(),
+            r1080p(AspectRatio::FOUR_THREE).to_key_string()
         );
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn reflect_round_trip() {
+        use bevy_reflect::{FromReflect, Struct};
+
+        let set = r1080p(AspectRatio::SIXTEEN_NINE);
+        let dynamic = Resolution::new(1366., 768.);
+
+        for res in [set, dynamic] {
+            let reflected = res.to_dynamic_struct();
+
+            assert_eq!(Resolution::from_reflect(&reflected), Some(res));
+        }
+    }
+
+    #[cfg(feature = "bevy_window")]
+    #[test]
+    fn from_window() {
+        let window = bevy_window::Window {
+            resolution: bevy_window::WindowResolution::new(1280, 720)
+                .with_scale_factor_override(2.),
+            ..Default::default()
+        };
 
-        assert_eq!(r360.scale_and_keep_aspect_ratio(Vec2::new(1., 2.)), None);
         assert_eq!(
-            r360.scale_and_keep_aspect_ratio(Vec2::splat(2.)),
-            Some(r720p(AspectRatio::SIXTEEN_NINE))
+            Resolution::from_window(&window),
+            Resolution::new(640., 360.)
+        );
+        assert_eq!(
+            Resolution::from_window_physical(&window),
+            Resolution::new(1280., 720.)
         );
     }
 
+    #[cfg(feature = "bevy_window")]
     #[test]
-    fn changes() {
-        let r360 = r360p(AspectRatio::SIXTEEN_NINE);
-
-        assert_eq!(r360.change_height(720., false).width(), 640.);
-        assert_eq!(r360.change_height(720., true).width(), 1280.);
-        assert_eq!(r360.change_width(1280., true).height(), 720.);
-        assert_eq!(r360.change_width(1280., false).height(), 360.);
+    fn from_window_scaled() {
+        let window = bevy_window::Window {
+            resolution: bevy_window::WindowResolution::new(1920, 1080)
+                .with_scale_factor_override(1.5),
+            ..Default::default()
+        };
+        let (logical, scale_factor) = Resolution::from_window_scaled(&window);
+
+        assert_eq!(logical, Resolution::new(1280., 720.));
+        assert_eq!(scale_factor, 1.5);
         assert_eq!(
-            UVec2::from(r360.change_ratio(AspectRatio::FOUR_THREE)),
-            UVec2::new(480, 360)
+            logical.as_physical(scale_factor),
+            Resolution::from_window_physical(&window)
         );
+    }
+
+    #[cfg(feature = "bevy_window")]
+    #[test]
+    fn window_resolution_round_trip() {
+        let r240 = Resolution::from_height(240., AspectRatio::SIXTEEN_NINE);
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        for res in [r240, r1080] {
+            let round_trip = Resolution::from_window_resolution(&res.to_window_resolution());
+
+            assert_eq!(UVec2::from(round_trip), UVec2::from(res));
+        }
         assert_eq!(
-            UVec2::from(r360.change_ratio(AspectRatio::ULTRAWIDE)),
-            UVec2::new(840, 360)
+            UVec2::from(Resolution::from_window_resolution(
+                &r240.to_window_resolution()
+            )),
+            UVec2::new(427, 240)
         );
     }
 
+    #[cfg(feature = "bevy_camera")]
+    #[test]
+    fn to_scaling_mode() {
+        use bevy_camera::ScalingMode;
+        let r360 = r360p(AspectRatio::SIXTEEN_NINE);
+
+        assert!(matches!(
+            r360.to_scaling_mode_fixed(),
+            ScalingMode::Fixed {
+                width: 640.,
+                height: 360.
+            }
+        ));
+        assert!(matches!(
+            r360.to_scaling_mode_auto(false),
+            ScalingMode::AutoMin {
+                min_width: 640.,
+                min_height: 360.
+            }
+        ));
+        assert!(matches!(
+            r360.to_scaling_mode_auto(true),
+            ScalingMode::AutoMax {
+                max_width: 640.,
+                max_height: 360.
+            }
+        ));
+    }
+
+    #[cfg(feature = "bevy_camera")]
+    #[test]
+    fn to_viewport() {
+        let r240 = Resolution::from_height(240., AspectRatio::SIXTEEN_NINE);
+        let viewport = r240.to_viewport(UVec2::new(10, 20));
+
+        assert_eq!(viewport.physical_position, UVec2::new(10, 20));
+        assert_eq!(viewport.physical_size, UVec2::from(r240));
+    }
+
+    #[cfg(feature = "bevy_window")]
+    #[test]
+    fn component() {
+        let mut world = bevy_ecs::world::World::new();
+        let r720 = r720p(AspectRatio::SIXTEEN_NINE);
+        let entity = world.spawn(r720).id();
+
+        let mut query = world.query::<&Resolution>();
+        assert_eq!(query.get(&world, entity).ok(), Some(&r720));
+    }
+
+    #[cfg(feature = "bevy_render")]
+    #[test]
+    fn to_extent() {
+        let extent = r1080p(AspectRatio::SIXTEEN_NINE).to_extent(6);
+
+        assert_eq!(extent.width, 1920);
+        assert_eq!(extent.height, 1080);
+        assert_eq!(extent.depth_or_array_layers, 6);
+    }
+
+    #[cfg(feature = "bevy_render")]
+    #[test]
+    fn limits() {
+        let limits = bevy_render::settings::WgpuLimits {
+            max_texture_dimension_2d: 2048,
+            ..Default::default()
+        };
+        let r4k = Resolution::from_height(2160., AspectRatio::SIXTEEN_NINE);
+        let r1080 = r1080p(AspectRatio::SIXTEEN_NINE);
+
+        assert!(!r4k.fits_limits(&limits));
+        assert!(r1080.fits_limits(&limits));
+        assert!(!Resolution::new(2048.5, 100.).fits_limits(&limits));
+
+        let clamped = r4k.clamp_to_limits(&limits);
+        assert_eq!(UVec2::from(clamped), UVec2::new(2048, 1152));
+        assert_eq!(clamped.aspect_ratio(), AspectRatio::SIXTEEN_NINE);
+        assert!(clamped.fits_limits(&limits));
+        assert_eq!(r1080.clamp_to_limits(&limits), r1080);
+    }
+
     #[cfg(feature = "bevy_window")]
     #[test]
     fn resolution_to_window() {
@@ -0,0 +1,109 @@
+//! Animated window resizes.
+//!
+//! Windows with a [`ResolutionTween`] have their physical size interpolated from one resolution to
+//! another over time by [`tween_window_resolutions`].
+use crate::resolutions::Resolution;
+use crate::window::WindowResolutionExt;
+use bevy_ecs::prelude::{Commands, Component, Entity, Query, Res};
+use bevy_time::Time;
+use bevy_window::Window;
+
+/// Animates a window's physical size from `from` to `to` over `duration` seconds.
+///
+/// The component is removed from the window once `elapsed` reaches `duration`.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
+pub struct ResolutionTween {
+    pub from: Resolution,
+    pub to: Resolution,
+    pub duration: f32,
+    pub elapsed: f32,
+}
+
+impl ResolutionTween {
+    /// Creates a tween from `from` to `to` lasting `duration` seconds, starting at `from`.
+    pub fn new(from: Resolution, to: Resolution, duration: f32) -> Self {
+        ResolutionTween {
+            from,
+            to,
+            duration,
+            elapsed: 0.,
+        }
+    }
+
+    /// Returns the resolution at the tween's current point. Tweens with no duration are
+    /// immediately at `to`.
+    pub fn current(&self) -> Resolution {
+        if self.duration > 0. {
+            self.from.lerp(&self.to, self.elapsed / self.duration)
+        } else {
+            self.to
+        }
+    }
+
+    /// Whether the tween has reached `to`.
+    pub fn is_finished(&self) -> bool {
+        self.elapsed >= self.duration
+    }
+}
+
+/// Advances every [`ResolutionTween`] and applies its current resolution to its window, removing
+/// the tween once it finishes.
+pub fn tween_window_resolutions(
+    time: Res<Time>,
+    mut commands: Commands,
+    mut windows: Query<(Entity, &mut Window, &mut ResolutionTween)>,
+) {
+    for (entity, mut window, mut tween) in &mut windows {
+        tween.elapsed += time.delta_secs();
+        window.resolution.apply_resolution(&tween.current());
+
+        if tween.is_finished() {
+            commands.entity(entity).remove::<ResolutionTween>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_ecs::world::World;
+    use bevy_math::{AspectRatio, UVec2};
+    use bevy_window::WindowResolution;
+    use std::time::Duration;
+
+    #[test]
+    fn tween_window() {
+        let mut world = World::new();
+        world.insert_resource(Time::<()>::default());
+        let from = Resolution::from_height(360., AspectRatio::SIXTEEN_NINE);
+        let to = Resolution::from_height(720., AspectRatio::SIXTEEN_NINE);
+        let window = world
+            .spawn((
+                Window {
+                    resolution: WindowResolution::new(640, 360),
+                    ..Default::default()
+                },
+                ResolutionTween::new(from, to, 1.),
+            ))
+            .id();
+
+        let step = |world: &mut World| {
+            world
+                .resource_mut::<Time>()
+                .advance_by(Duration::from_millis(250));
+            world.run_system_once(tween_window_resolutions).unwrap();
+            world.get::<Window>(window).unwrap().physical_size()
+        };
+
+        assert_eq!(step(&mut world), UVec2::new(800, 450));
+        assert_eq!(step(&mut world), UVec2::new(960, 540));
+        assert_eq!(step(&mut world), UVec2::new(1120, 630));
+        assert!(world.get::<ResolutionTween>(window).is_some());
+
+        assert_eq!(step(&mut world), UVec2::new(1280, 720));
+        assert!(world.get::<ResolutionTween>(window).is_none());
+
+        assert_eq!(step(&mut world), UVec2::new(1280, 720));
+    }
+}
@@ -0,0 +1,98 @@
+//! Extensions for `bevy_window` types.
+use crate::resolutions::Resolution;
+use bevy_math::UVec2;
+use bevy_window::WindowResolution;
+
+/// Extension methods for working with [`Resolution`]s directly on a [`WindowResolution`].
+pub trait WindowResolutionExt {
+    /// Returns the window's physical size as a dynamic [`Resolution`].
+    fn to_resolution(&self) -> Resolution;
+
+    /// Sets the window's physical size to `res`, rounding each dimension up to whole pixels.
+    fn apply_resolution(&mut self, res: &Resolution);
+}
+
+impl WindowResolutionExt for WindowResolution {
+    fn to_resolution(&self) -> Resolution {
+        Resolution::from_window_resolution(self)
+    }
+
+    fn apply_resolution(&mut self, res: &Resolution) {
+        let size = UVec2::from(*res);
+        self.set_physical_resolution(size.x, size.y);
+    }
+}
+
+/// A physical [`Resolution`] paired with the scale factor it is displayed at.
+///
+/// Converting a [`WindowResolution`] into a plain [`Resolution`] drops its scale factor, this keeps
+/// it so it survives a round trip, such as when persisting HiDPI settings. The scale factor is
+/// restored as a scale factor override so that it takes precedence over the one reported by the
+/// window backend.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScaledResolution {
+    /// The size in physical pixels
+    pub resolution: Resolution,
+    /// The ratio of physical pixels to logical pixels
+    pub scale_factor: f32,
+}
+
+impl From<&WindowResolution> for ScaledResolution {
+    fn from(value: &WindowResolution) -> Self {
+        ScaledResolution {
+            resolution: value.to_resolution(),
+            scale_factor: value.scale_factor(),
+        }
+    }
+}
+
+impl From<WindowResolution> for ScaledResolution {
+    fn from(value: WindowResolution) -> Self {
+        ScaledResolution::from(&value)
+    }
+}
+
+impl From<ScaledResolution> for WindowResolution {
+    fn from(value: ScaledResolution) -> Self {
+        value
+            .resolution
+            .to_window_resolution()
+            .with_scale_factor_override(value.scale_factor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::AspectRatio;
+
+    #[test]
+    fn to_resolution() {
+        let window = WindowResolution::new(1920, 1080);
+
+        assert_eq!(window.to_resolution(), Resolution::new(1920., 1080.));
+    }
+
+    #[test]
+    fn apply_resolution() {
+        let mut window = WindowResolution::new(1920, 1080);
+        window.apply_resolution(&Resolution::from_height(240., AspectRatio::SIXTEEN_NINE));
+
+        assert_eq!(window.physical_size(), UVec2::new(427, 240));
+    }
+
+    #[test]
+    fn scaled_resolution() {
+        let window = WindowResolution::new(2560, 1440).with_scale_factor_override(1.5);
+        let scaled = ScaledResolution::from(&window);
+
+        assert_eq!(scaled.resolution, Resolution::new(2560., 1440.));
+        assert_eq!(scaled.scale_factor, 1.5);
+
+        let restored = WindowResolution::from(scaled);
+        assert_eq!(restored.physical_size(), UVec2::new(2560, 1440));
+        assert_eq!(restored.scale_factor(), 1.5);
+        assert_eq!(restored.size(), window.size());
+        assert_eq!(ScaledResolution::from(restored), scaled);
+    }
+}
@@ -0,0 +1,29 @@
+//! Runs in its own test binary, as it replaces the process-wide panic hook.
+
+use std::panic;
+use std::sync::{Arc, Mutex};
+
+use bevy_resolution::prelude::*;
+
+#[test]
+fn aspect_ratio_panic_reports_caller() {
+    let location = Arc::new(Mutex::new(None));
+    let hook_location = Arc::clone(&location);
+    let previous_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        *hook_location.lock().unwrap() =
+            info.location().map(|at| (at.file().to_string(), at.line()));
+    }));
+
+    let resolution = Resolution::new(1920., 0.);
+    let expected_line = line!() + 1;
+    let result = panic::catch_unwind(|| resolution.aspect_ratio());
+    panic::set_hook(previous_hook);
+
+    let message = result.unwrap_err().downcast::<String>().unwrap();
+    assert_eq!(*message, "invalid aspect ratio: 1920:0");
+    assert_eq!(
+        *location.lock().unwrap(),
+        Some((file!().to_string(), expected_line))
+    );
+}